@@ -1,6 +1,7 @@
+use std::collections::HashMap;
+use std::io::{Read, Write};
 use std::net::TcpStream;
-use std::process::Command;
-use std::sync::{Arc, Mutex};
+use std::sync::{mpsc, Arc, Mutex};
 use std::time::{Duration, Instant};
 use tauri::Manager;
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
@@ -10,6 +11,7 @@ mod process;
 
 const BACKEND_PORT: u16 = 40000;
 const PROCESS_KILL_DELAY_MS: u64 = 500;
+const GRACEFUL_TERMINATION_TIMEOUT_MS: u64 = 5000;
 const SPAWN_COOLDOWN_SECS: u64 = 3;
 const HEALTH_CHECK_CONNECT_TIMEOUT_SECS: u64 = 5;
 const HEALTH_CHECK_MAX_TIME_SECS: u64 = 8;
@@ -18,6 +20,37 @@ const PORT_CHECK_INTERVAL_MS: u64 = 100;
 const BACKEND_READY_CHECK_INTERVAL_MS: u64 = 1000;
 const PROGRESS_LOG_INTERVAL_SECS: u64 = 5;
 const BACKEND_READY_MAX_WAIT_SECS: u64 = 60;
+const GRACEFUL_SHUTDOWN_TIMEOUT_SECS: u64 = 5;
+const RESTART_BACKOFF_BASE_SECS: u64 = 1;
+const RESTART_BACKOFF_MAX_SECS: u64 = 30;
+const RESTART_STABLE_WINDOW_SECS: u64 = 60;
+const CRASH_BUDGET_MAX_RESTARTS: usize = 5;
+const CRASH_BUDGET_WINDOW_SECS: u64 = 60;
+/// Candidate ports for the blue-green swap: the replacement sidecar always binds whichever
+/// of these isn't currently active.
+const BACKEND_PORT_POOL: [u16; 2] = [BACKEND_PORT, BACKEND_PORT + 1];
+const BLUE_GREEN_HEALTH_TIMEOUT_SECS: u64 = 30;
+const SIDECAR_REQUEST_TIMEOUT_SECS: u64 = 10;
+
+/// Identifies one sidecar the [`SidecarRegistry`] knows how to spawn: the name it's keyed and
+/// addressed by in commands, the sidecar binary id as registered in `tauri.conf.json`, and the
+/// port it's expected to bind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct SidecarSpec {
+    name: &'static str,
+    binary: &'static str,
+    port: u16,
+}
+
+const MAIN_SIDECAR: SidecarSpec = SidecarSpec { name: "main", binary: "main", port: BACKEND_PORT };
+
+/// Sidecars the app knows how to spawn by name. Add an entry here to run another supervised
+/// process alongside the main backend, e.g. a GPU worker.
+const SIDECAR_SPECS: &[SidecarSpec] = &[MAIN_SIDECAR];
+
+fn find_sidecar_spec(name: &str) -> Option<SidecarSpec> {
+    SIDECAR_SPECS.iter().copied().find(|spec| spec.name == name)
+}
 
 #[derive(Debug)]
 struct SidecarState {
@@ -26,16 +59,49 @@ struct SidecarState {
     pid: Option<u32>,
     spawn_time: Option<Instant>,
     should_run: bool,
+    /// Number of consecutive restarts since the sidecar last stayed up for
+    /// [`RESTART_STABLE_WINDOW_SECS`]; drives the exponential backoff delay.
+    consecutive_restarts: u32,
+    /// Timestamps of restarts within the rolling [`CRASH_BUDGET_WINDOW_SECS`] window,
+    /// used to enforce the crash budget.
+    restart_attempts: Vec<Instant>,
+    /// Set once the crash budget is exceeded; the supervisor gives up and the UI
+    /// should surface this as a terminal failure rather than keep retrying.
+    failed: bool,
+    /// Port the active sidecar is currently bound to.
+    active_port: u16,
+    /// The replacement sidecar during a blue-green restart, spawned on
+    /// [`alternate_port`] and promoted to active once it passes its health check.
+    pending: Option<PendingSidecar>,
+    /// Channels awaiting a reply to an in-flight [`send_sidecar_request`], keyed by the
+    /// request id that was sent out over the control channel.
+    pending_replies: HashMap<u64, mpsc::Sender<serde_json::Value>>,
+    /// Id to use for the next request sent to this sidecar; incremented on every send.
+    next_request_id: u64,
+}
+
+/// A sidecar spawned on an alternate port during a blue-green restart, not yet serving
+/// traffic. Promoted to the active slot by [`promote_pending_sidecar`] once it's healthy.
+#[derive(Debug)]
+struct PendingSidecar {
+    child: tauri_plugin_shell::process::CommandChild,
+    pid: u32,
+    port: u16,
 }
 
 impl Drop for SidecarState {
     fn drop(&mut self) {
         info!("SidecarState dropping, cleaning up process");
+        if let Some(pending) = self.pending.take() {
+            if !graceful_stop_sidecar(pending.pid) {
+                let _ = pending.child.kill();
+            }
+        }
         if let Some(child) = self.child.take() {
             let pid = child.pid();
-            info!("Killing sidecar process tree (PID: {})", pid);
-            kill_process_tree(pid);
-            let _ = child.kill();
+            if !graceful_stop_sidecar(pid) {
+                let _ = child.kill();
+            }
         }
     }
 }
@@ -48,19 +114,121 @@ impl SidecarState {
             pid: None,
             spawn_time: None,
             should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
         }
     }
 }
 
+/// Whichever port in [`BACKEND_PORT_POOL`] isn't `current`, for the blue-green restart to
+/// spawn the replacement sidecar on.
+fn alternate_port(current: u16) -> u16 {
+    BACKEND_PORT_POOL
+        .into_iter()
+        .find(|&port| port != current)
+        .unwrap_or(current)
+}
+
+fn active_port(sidecar_state: &SidecarChild) -> u16 {
+    sidecar_state.lock().map(|state| state.active_port).unwrap_or(BACKEND_PORT)
+}
+
+/// What [`handle_terminated_event`] decided to do about a sidecar that just exited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RestartDecision {
+    /// Re-spawn the sidecar after waiting out the given backoff.
+    Restart(Duration),
+    /// Don't restart: shutdown is in progress, or this event was stale.
+    Stopped,
+    /// Don't restart: the crash budget was exceeded and the sidecar is now failed.
+    CrashBudgetExceeded,
+}
+
 type SidecarChild = Arc<Mutex<SidecarState>>;
 
+/// Every sidecar the app has spawned, keyed by [`SidecarSpec::name`]. Lets commands address a
+/// specific sidecar by name instead of the app wiring a single hardcoded `SidecarChild` through
+/// every function, the way `"main"` used to be the only one that existed.
+#[derive(Debug, Clone, Default)]
+struct SidecarRegistry {
+    sidecars: Arc<Mutex<HashMap<String, SidecarChild>>>,
+}
+
+impl SidecarRegistry {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `sidecar_state` under `name`, replacing whatever was there before.
+    fn insert(&self, name: &str, sidecar_state: SidecarChild) {
+        self.sidecars
+            .lock()
+            .expect("sidecar registry lock poisoned")
+            .insert(name.to_string(), sidecar_state);
+    }
+
+    /// Returns the `SidecarChild` registered under `name`, creating a fresh, not-yet-spawned
+    /// one if this is the first time it's been referenced.
+    fn entry(&self, name: &str) -> SidecarChild {
+        self.sidecars
+            .lock()
+            .expect("sidecar registry lock poisoned")
+            .entry(name.to_string())
+            .or_insert_with(|| Arc::new(Mutex::new(SidecarState::new())))
+            .clone()
+    }
+
+    fn get(&self, name: &str) -> Option<SidecarChild> {
+        self.sidecars.lock().expect("sidecar registry lock poisoned").get(name).cloned()
+    }
+}
+
+/// Snapshot of a named sidecar's state for the frontend, returned by [`sidecar_status`].
+#[derive(Debug, Clone, serde::Serialize)]
+struct SidecarStatus {
+    running: bool,
+    ready: bool,
+    pid: Option<u32>,
+    port: u16,
+    failed: bool,
+}
+
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
-fn kill_process_tree(pid: u32) {
-    process::kill_process_tree(pid);
+fn kill_process_tree(pid: u32) -> process::TerminationOutcome {
+    let outcome = process::kill_process_tree(pid);
+    info!("Sidecar tree termination outcome for PID {}: {:?}", pid, outcome);
+    outcome
+}
+
+/// Asks the sidecar to shut down cleanly, giving it up to `GRACEFUL_SHUTDOWN_TIMEOUT_SECS`
+/// to flush state and close its listening socket before force-killing the tree. Returns
+/// `true` if it exited on its own.
+fn graceful_stop_sidecar(pid: u32) -> bool {
+    info!("Requesting graceful shutdown of sidecar (PID: {})", pid);
+    if !process::send_graceful_signal(pid) {
+        warn!("Failed to send graceful termination signal to sidecar (PID: {})", pid);
+    }
+
+    if process::wait_with_timeout(pid, Duration::from_secs(GRACEFUL_SHUTDOWN_TIMEOUT_SECS)) {
+        info!("Sidecar (PID: {}) exited gracefully", pid);
+        true
+    } else {
+        warn!(
+            "Sidecar (PID: {}) did not exit within {}s, forcing termination",
+            pid, GRACEFUL_SHUTDOWN_TIMEOUT_SECS
+        );
+        kill_process_tree(pid);
+        false
+    }
 }
 
 pub fn is_port_available(port: u16) -> bool {
@@ -68,66 +236,75 @@ pub fn is_port_available(port: u16) -> bool {
 }
 
 pub fn is_backend_healthy() -> bool {
+    is_backend_healthy_on(BACKEND_PORT)
+}
 
-    if is_port_available(BACKEND_PORT) {
+pub fn is_backend_healthy_on(port: u16) -> bool {
+    if is_port_available(port) {
         return false;
     }
 
-    match Command::new("curl")
-        .args(&[
-            "-s",
-            "-f",
-            "--connect-timeout",
-            &HEALTH_CHECK_CONNECT_TIMEOUT_SECS.to_string(),
-            "--max-time",
-            &HEALTH_CHECK_MAX_TIME_SECS.to_string(),
-            &format!("http://127.0.0.1:{}/api/health", BACKEND_PORT),
-        ])
-        .output()
-    {
-        Ok(output) if output.status.success() => String::from_utf8(output.stdout)
-            .map(|s| s.contains(r#""status":"healthy""#))
-            .unwrap_or(false),
-        Ok(output) => {
-            debug!("Health check curl failed: {}", String::from_utf8_lossy(&output.stderr));
-            false
-        }
+    match http_get(port, "/api/health") {
+        Ok(body) => body.contains(r#""status":"healthy""#),
         Err(e) => {
-            debug!("Health check curl error: {}", e);
+            debug!("Health check request failed: {}", e);
             false
         }
     }
 }
 
-fn kill_existing_backend() -> Result<(), Box<dyn std::error::Error>> {
-    info!("Cleaning up any existing backend processes...");
+/// Plain HTTP/1.0 GET over a raw [`TcpStream`], returning the response body. Replaces the
+/// `curl` subprocess this used to shell out to: asking the sidecar for `/api/health` and
+/// reading back a body doesn't need an external process or a full HTTP client.
+fn http_get(port: u16, path: &str) -> std::io::Result<String> {
+    let mut stream = TcpStream::connect_timeout(
+        &format!("127.0.0.1:{}", port).parse().expect("127.0.0.1:<port> is always a valid socket address"),
+        Duration::from_secs(HEALTH_CHECK_CONNECT_TIMEOUT_SECS),
+    )?;
+    stream.set_read_timeout(Some(Duration::from_secs(HEALTH_CHECK_MAX_TIME_SECS)))?;
+    stream.set_write_timeout(Some(Duration::from_secs(HEALTH_CHECK_MAX_TIME_SECS)))?;
+
+    write!(stream, "GET {} HTTP/1.0\r\nHost: 127.0.0.1\r\nConnection: close\r\n\r\n", path)?;
 
-    process::kill_process_on_port(BACKEND_PORT)?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
 
-    wait_for_port_release()
+    Ok(response.split("\r\n\r\n").nth(1).unwrap_or("").to_string())
 }
 
-fn wait_for_port_release() -> Result<(), Box<dyn std::error::Error>> {
+fn kill_existing_backend(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    info!("Cleaning up any existing backend processes on port {}...", port);
+
+    process::kill_process_on_port(port)?;
+
+    wait_for_port_release(port)
+}
+
+fn wait_for_port_release(port: u16) -> Result<(), Box<dyn std::error::Error>> {
     let start = Instant::now();
-    while !is_port_available(BACKEND_PORT) && start.elapsed() < Duration::from_secs(PORT_CLEANUP_TIMEOUT_SECS) {
+    while !is_port_available(port) && start.elapsed() < Duration::from_secs(PORT_CLEANUP_TIMEOUT_SECS) {
         std::thread::sleep(Duration::from_millis(PORT_CHECK_INTERVAL_MS));
     }
 
-    if is_port_available(BACKEND_PORT) {
+    if is_port_available(port) {
         info!("Backend cleanup complete");
         Ok(())
     } else {
-        Err(format!("Failed to free port {}", BACKEND_PORT).into())
+        Err(format!("Failed to free port {}", port).into())
     }
 }
 
 pub fn wait_for_backend_ready(max_wait: Duration) -> bool {
-    info!("Waiting for backend to be ready...");
+    wait_for_backend_ready_on(BACKEND_PORT, max_wait)
+}
+
+pub fn wait_for_backend_ready_on(port: u16, max_wait: Duration) -> bool {
+    info!("Waiting for backend on port {} to be ready...", port);
     let start = Instant::now();
     let mut last_log = Instant::now();
 
     while start.elapsed() < max_wait {
-        if is_backend_healthy() {
+        if is_backend_healthy_on(port) {
             info!("Backend is ready after {:?}", start.elapsed());
             return true;
         }
@@ -151,24 +328,26 @@ fn is_process_running(pid: u32) -> bool {
 fn spawn_sidecar_process(
     app_handle: &tauri::AppHandle,
     sidecar_state: &SidecarChild,
+    spec: SidecarSpec,
+    port: u16,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if !should_spawn_sidecar(sidecar_state)? {
         return Ok(());
     }
 
-    if is_backend_already_ready(sidecar_state)? {
+    if is_backend_already_ready(sidecar_state, port)? {
         return Ok(());
     }
 
-    info!("Starting Python backend sidecar...");
-    prepare_backend_environment()?;
+    info!("Starting '{}' sidecar on port {}...", spec.name, port);
+    prepare_backend_environment(port)?;
 
-    let (rx, child_process) = create_and_spawn_sidecar(app_handle)?;
+    let (rx, child_process) = create_and_spawn_sidecar(app_handle, spec, port)?;
     let pid = child_process.pid();
-    info!("Sidecar spawned with PID: {}", pid);
+    info!("Sidecar '{}' spawned with PID: {}", spec.name, pid);
 
-    update_sidecar_state(sidecar_state, child_process, pid)?;
-    start_sidecar_monitoring(rx, sidecar_state, pid);
+    update_sidecar_state(sidecar_state, child_process, pid, port)?;
+    start_sidecar_monitoring(rx, sidecar_state, pid, app_handle, spec);
 
     Ok(())
 }
@@ -200,8 +379,8 @@ fn should_spawn_sidecar(sidecar_state: &SidecarChild) -> Result<bool, Box<dyn st
     Ok(true)
 }
 
-fn is_backend_already_ready(sidecar_state: &SidecarChild) -> Result<bool, Box<dyn std::error::Error>> {
-    if is_backend_healthy() {
+fn is_backend_already_ready(sidecar_state: &SidecarChild, port: u16) -> Result<bool, Box<dyn std::error::Error>> {
+    if is_backend_healthy_on(port) {
         info!("Backend already healthy, updating state");
         let mut state = sidecar_state
             .lock()
@@ -212,31 +391,90 @@ fn is_backend_already_ready(sidecar_state: &SidecarChild) -> Result<bool, Box<dy
     Ok(false)
 }
 
-fn prepare_backend_environment() -> Result<(), Box<dyn std::error::Error>> {
-    if !is_port_available(BACKEND_PORT) {
-        warn!("Port {} in use but backend not healthy, cleaning up", BACKEND_PORT);
-        kill_existing_backend()?;
+fn prepare_backend_environment(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+    if !is_port_available(port) {
+        warn!("Port {} in use but backend not healthy, cleaning up", port);
+        kill_existing_backend(port)?;
     }
     Ok(())
 }
 
 fn create_and_spawn_sidecar(
     app_handle: &tauri::AppHandle,
+    spec: SidecarSpec,
+    port: u16,
 ) -> Result<(tauri::async_runtime::Receiver<CommandEvent>, tauri_plugin_shell::process::CommandChild), Box<dyn std::error::Error>> {
     let sidecar_command = app_handle
         .shell()
-        .sidecar("main")
-        .map_err(|e| format!("Failed to create sidecar command: {}", e))?;
+        .sidecar(spec.binary)
+        .map_err(|e| format!("Failed to create sidecar command: {}", e))?
+        .args(["--port", &port.to_string()]);
 
     sidecar_command
         .spawn()
         .map_err(|e| format!("Failed to spawn sidecar: {}", e).into())
 }
 
+/// Encodes `payload` as a length-prefixed control frame — the same `{packet, N}` framing
+/// [`FrameDecoder`] reads back on stdout — and writes it to the sidecar's stdin.
+fn write_control_frame(
+    child: &mut tauri_plugin_shell::process::CommandChild,
+    payload: &serde_json::Value,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let body = serde_json::to_vec(payload)?;
+    let mut frame = (body.len() as u32).to_be_bytes().to_vec();
+    frame.extend_from_slice(&body);
+    child.write(frame)?;
+    Ok(())
+}
+
+/// Sends `request` to a running sidecar over its stdin and blocks for the matching reply,
+/// correlated by request id the same way the control channel already correlates frames by
+/// `type`. Building the request/reply path on the same length-prefixed framing as the
+/// readiness/health events (see [`FrameDecoder`]) means both directions of sidecar IPC speak
+/// one wire format instead of a one-off protocol bolted on for requests.
+fn send_sidecar_request(
+    sidecar_state: &SidecarChild,
+    request: serde_json::Value,
+) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let (tx, rx) = mpsc::channel();
+
+    let id = {
+        let mut state = sidecar_state
+            .lock()
+            .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+
+        if state.child.is_none() {
+            return Err("Sidecar is not running".into());
+        }
+
+        let id = state.next_request_id;
+        state.next_request_id += 1;
+
+        let envelope = serde_json::json!({ "type": "request", "id": id, "payload": request });
+        write_control_frame(state.child.as_mut().expect("checked above"), &envelope)?;
+
+        state.pending_replies.insert(id, tx);
+        id
+    };
+
+    let reply = rx.recv_timeout(Duration::from_secs(SIDECAR_REQUEST_TIMEOUT_SECS));
+    if reply.is_err() {
+        // The reply never arrived (or never will): drop our slot so a hung or restarting
+        // sidecar doesn't leak one `pending_replies` entry per timed-out request.
+        if let Ok(mut state) = sidecar_state.lock() {
+            state.pending_replies.remove(&id);
+        }
+    }
+
+    reply.map_err(|_| "Timed out waiting for sidecar reply".into())
+}
+
 fn update_sidecar_state(
     sidecar_state: &SidecarChild,
     child_process: tauri_plugin_shell::process::CommandChild,
     pid: u32,
+    port: u16,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut state = sidecar_state
         .lock()
@@ -245,6 +483,7 @@ fn update_sidecar_state(
     state.pid = Some(pid);
     state.is_ready = false;
     state.spawn_time = Some(Instant::now());
+    state.active_port = port;
     Ok(())
 }
 
@@ -252,101 +491,559 @@ fn start_sidecar_monitoring(
     mut rx: tauri::async_runtime::Receiver<CommandEvent>,
     sidecar_state: &SidecarChild,
     pid: u32,
+    app_handle: &tauri::AppHandle,
+    spec: SidecarSpec,
 ) {
     let sidecar_monitor = Arc::clone(sidecar_state);
+    let app_handle = app_handle.clone();
     tauri::async_runtime::spawn(async move {
-        let mut backend_ready = false;
+        let mut control_frames = FrameDecoder::new();
 
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(bytes) => {
-                    handle_stdout_event(bytes);
+                    handle_stdout_event(bytes, &mut control_frames, &sidecar_monitor);
                 }
                 CommandEvent::Stderr(bytes) => {
-                    backend_ready = handle_stderr_event(bytes, backend_ready, &sidecar_monitor);
+                    handle_stderr_event(bytes);
                 }
                 CommandEvent::Error(err) => {
                     error!("sidecar error: {}", err);
                 }
                 CommandEvent::Terminated(payload) => {
-                    handle_terminated_event(payload, &sidecar_monitor, pid);
+                    if let RestartDecision::Restart(backoff) =
+                        handle_terminated_event(payload, &sidecar_monitor, pid)
+                    {
+                        schedule_restart(app_handle.clone(), Arc::clone(&sidecar_monitor), backoff, spec);
+                    }
                     break;
                 }
                 _ => {}
             }
         }
     });
+
+    start_exit_reaper(Arc::clone(sidecar_state), pid, app_handle.clone(), spec);
 }
 
-fn handle_stdout_event(bytes: Vec<u8>) {
-    let output = String::from_utf8_lossy(&bytes);
-    let trimmed = output.trim();
-    if !trimmed.is_empty() {
-        debug!("sidecar stdout: {}", trimmed);
+/// Backstops the Tauri shell's `Terminated` event with the pidfd-based reaper from
+/// [`process::r#async::wait_for_exit`]: if that event is ever dropped or delayed, this notices
+/// the sidecar's exit independently and drives the same restart decision, with the real exit
+/// code/signal from `waitpid` rather than whatever Tauri's shell plugin reported. Racing this
+/// against the event loop in [`start_sidecar_monitoring`] is safe because `handle_terminated_event`
+/// already ignores a termination whose PID no longer matches the state's current one — whichever
+/// path notices first wins, and the other lands on a PID that's already been cleared.
+#[cfg(not(feature = "shell-fallback"))]
+fn start_exit_reaper(sidecar_state: SidecarChild, pid: u32, app_handle: tauri::AppHandle, spec: SidecarSpec) {
+    tauri::async_runtime::spawn(async move {
+        let exit = process::r#async::wait_for_exit(pid).await;
+        debug!("Exit reaper observed sidecar (PID: {}) exit: {:?}", pid, exit);
+
+        let payload = tauri_plugin_shell::process::TerminatedPayload {
+            code: exit.exit_code,
+            signal: exit.signal,
+        };
+
+        if let RestartDecision::Restart(backoff) = handle_terminated_event(payload, &sidecar_state, pid) {
+            schedule_restart(app_handle, sidecar_state, backoff, spec);
+        }
+    });
+}
+
+/// Under `shell-fallback`, [`process::r#async`] isn't compiled, so the Tauri shell's own
+/// `Terminated` event remains the only exit signal.
+#[cfg(feature = "shell-fallback")]
+fn start_exit_reaper(_sidecar_state: SidecarChild, _pid: u32, _app_handle: tauri::AppHandle, _spec: SidecarSpec) {}
+
+/// Re-spawns the sidecar after `backoff`, run off the async executor since it blocks on
+/// `spawn_sidecar_process`'s synchronous environment/port checks.
+fn schedule_restart(app_handle: tauri::AppHandle, sidecar_state: SidecarChild, backoff: Duration, spec: SidecarSpec) {
+    std::thread::spawn(move || {
+        info!("Restarting sidecar in {:?}", backoff);
+        std::thread::sleep(backoff);
+        let port = active_port(&sidecar_state);
+        if let Err(e) = spawn_sidecar_process(&app_handle, &sidecar_state, spec, port) {
+            error!("Automatic sidecar restart failed: {}", e);
+        }
+    });
+}
+
+/// Accumulates raw sidecar stdout bytes and extracts complete control frames: a 4-byte
+/// big-endian length prefix followed by that many bytes of JSON payload. This is the
+/// `{packet, N}` framing Erlang uses over its port interface, applied to our sidecar so it
+/// can report readiness and health explicitly instead of us log-sniffing stderr.
+#[derive(Debug, Default)]
+struct FrameDecoder {
+    buf: Vec<u8>,
+}
+
+impl FrameDecoder {
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds newly read bytes in and returns any frame payloads that are now complete.
+    fn push(&mut self, bytes: &[u8]) -> Vec<Vec<u8>> {
+        self.buf.extend_from_slice(bytes);
+
+        let mut frames = Vec::new();
+        while self.buf.len() >= 4 {
+            let len = u32::from_be_bytes(self.buf[..4].try_into().unwrap()) as usize;
+            if self.buf.len() < 4 + len {
+                break;
+            }
+            frames.push(self.buf[4..4 + len].to_vec());
+            self.buf.drain(..4 + len);
+        }
+        frames
     }
 }
 
-fn handle_stderr_event(
-    bytes: Vec<u8>,
-    mut backend_ready: bool,
-    sidecar_monitor: &SidecarChild,
-) -> bool {
+/// A decoded sidecar control event, sent as JSON over the [`FrameDecoder`] channel.
+#[derive(Debug, Clone, PartialEq)]
+enum SidecarEvent {
+    Ready,
+    Health { healthy: bool },
+    ShuttingDown,
+    /// Reply to a request previously sent by [`send_sidecar_request`], correlated by `id`.
+    Reply { id: u64, payload: serde_json::Value },
+}
+
+fn parse_sidecar_event(payload: &[u8]) -> Option<SidecarEvent> {
+    let json: serde_json::Value = serde_json::from_slice(payload)
+        .map_err(|e| debug!("Malformed sidecar control frame: {}", e))
+        .ok()?;
+
+    match json.get("type").and_then(|t| t.as_str())? {
+        "ready" => Some(SidecarEvent::Ready),
+        "health" => Some(SidecarEvent::Health {
+            healthy: json.get("status").and_then(|s| s.as_str()) == Some("healthy"),
+        }),
+        "shutting_down" => Some(SidecarEvent::ShuttingDown),
+        "reply" => Some(SidecarEvent::Reply {
+            id: json.get("id").and_then(|i| i.as_u64())?,
+            payload: json.get("payload").cloned().unwrap_or(serde_json::Value::Null),
+        }),
+        other => {
+            debug!("Unknown sidecar control event type: {}", other);
+            None
+        }
+    }
+}
+
+fn apply_sidecar_event(event: SidecarEvent, sidecar_monitor: &SidecarChild) {
+    let Ok(mut state) = sidecar_monitor.lock() else {
+        error!("Failed to lock sidecar state while applying a control event");
+        return;
+    };
+
+    match event {
+        SidecarEvent::Ready => {
+            state.is_ready = true;
+            info!("Backend marked as ready");
+        }
+        SidecarEvent::Health { healthy } => {
+            debug!("Backend reported health: {}", healthy);
+            state.is_ready = healthy;
+        }
+        SidecarEvent::ShuttingDown => {
+            info!("Backend reported it is shutting down");
+            state.is_ready = false;
+        }
+        SidecarEvent::Reply { id, payload } => {
+            if let Some(tx) = state.pending_replies.remove(&id) {
+                let _ = tx.send(payload);
+            } else {
+                debug!("Reply for unknown or already-timed-out request id {}", id);
+            }
+        }
+    }
+}
+
+fn handle_stdout_event(bytes: Vec<u8>, control_frames: &mut FrameDecoder, sidecar_monitor: &SidecarChild) {
+    for frame in control_frames.push(&bytes) {
+        match parse_sidecar_event(&frame) {
+            Some(event) => apply_sidecar_event(event, sidecar_monitor),
+            None => debug!("Unparseable sidecar control frame ({} bytes)", frame.len()),
+        }
+    }
+}
+
+fn handle_stderr_event(bytes: Vec<u8>) {
     let output = String::from_utf8_lossy(&bytes);
     let trimmed = output.trim();
     if !trimmed.is_empty() {
         debug!("sidecar stderr: {}", trimmed);
 
-        if !backend_ready && trimmed.contains("Application startup complete") {
-            backend_ready = true;
-            if let Ok(mut state) = sidecar_monitor.lock() {
-                state.is_ready = true;
-                info!("Backend marked as ready");
-            }
-        }
-
         if trimmed.contains("Another instance is already running") {
             error!("Duplicate sidecar instance detected!");
         }
     }
-    backend_ready
 }
 
+/// Decides whether the sidecar that just terminated should be restarted, and updates
+/// `consecutive_restarts`/`restart_attempts`/`failed` accordingly. Doesn't do the actual
+/// respawning (that needs an `AppHandle`, which this function doesn't have) — the caller
+/// acts on the returned [`RestartDecision`] instead.
 fn handle_terminated_event(
     payload: tauri_plugin_shell::process::TerminatedPayload,
     sidecar_monitor: &SidecarChild,
     pid: u32,
-) {
+) -> RestartDecision {
     info!("Sidecar process terminated with code: {:?}", payload.code);
 
-    if let Ok(mut state) = sidecar_monitor.lock() {
-        if state.pid == Some(pid) {
-            state.child = None;
-            state.is_ready = false;
-            state.pid = None;
-            state.spawn_time = None;
+    let mut state = match sidecar_monitor.lock() {
+        Ok(state) => state,
+        Err(e) => {
+            error!("Failed to lock sidecar state after termination: {}", e);
+            return RestartDecision::Stopped;
+        }
+    };
+
+    if state.pid != Some(pid) {
+        debug!("Ignoring stale termination event for PID {}", pid);
+        return RestartDecision::Stopped;
+    }
+
+    if let Some(spawn_time) = state.spawn_time {
+        if spawn_time.elapsed() >= Duration::from_secs(RESTART_STABLE_WINDOW_SECS) {
+            debug!("Sidecar stayed up for {:?}, resetting restart backoff", spawn_time.elapsed());
+            state.consecutive_restarts = 0;
+        }
+    }
+
+    state.child = None;
+    state.is_ready = false;
+    state.pid = None;
+    state.spawn_time = None;
+
+    if !state.should_run {
+        info!("Sidecar process terminated - shutdown in progress, not restarting");
+        return RestartDecision::Stopped;
+    }
+
+    let now = Instant::now();
+    state
+        .restart_attempts
+        .retain(|t| now.duration_since(*t) < Duration::from_secs(CRASH_BUDGET_WINDOW_SECS));
+    state.restart_attempts.push(now);
+
+    if state.restart_attempts.len() > CRASH_BUDGET_MAX_RESTARTS {
+        error!(
+            "Sidecar crashed {} times within {}s, exceeding the crash budget - giving up",
+            state.restart_attempts.len(),
+            CRASH_BUDGET_WINDOW_SECS
+        );
+        state.should_run = false;
+        state.failed = true;
+        return RestartDecision::CrashBudgetExceeded;
+    }
+
+    let backoff_secs =
+        (RESTART_BACKOFF_BASE_SECS << state.consecutive_restarts.min(5)).min(RESTART_BACKOFF_MAX_SECS);
+    state.consecutive_restarts += 1;
+    let backoff = Duration::from_secs(backoff_secs);
+
+    info!(
+        "Sidecar process terminated unexpectedly - restarting in {:?} (attempt {})",
+        backoff, state.consecutive_restarts
+    );
+    RestartDecision::Restart(backoff)
+}
+
+/// Spawns a replacement sidecar on [`alternate_port`] and, once it reports healthy, atomically
+/// swaps it in as the active sidecar before retiring the old one. Unlike [`schedule_restart`],
+/// which replaces a sidecar that already died, this swaps one that's still serving requests, so
+/// a planned restart or upgrade doesn't drop traffic.
+fn restart_sidecar_blue_green(
+    app_handle: &tauri::AppHandle,
+    sidecar_state: &SidecarChild,
+    spec: SidecarSpec,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let old_port = active_port(sidecar_state);
+    let new_port = alternate_port(old_port);
+
+    {
+        let state = sidecar_state
+            .lock()
+            .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+        if state.pending.is_some() {
+            return Err("Blue-green restart already in progress".into());
         }
     }
 
-    info!("Sidecar process terminated - no automatic restart");
+    info!("Starting blue-green restart: spawning replacement sidecar on port {}", new_port);
+    prepare_backend_environment(new_port)?;
+    let (rx, child_process) = create_and_spawn_sidecar(app_handle, spec, new_port)?;
+    let pid = child_process.pid();
+    info!("Replacement sidecar spawned with PID: {} on port {}", pid, new_port);
+
+    {
+        let mut state = sidecar_state
+            .lock()
+            .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+        state.pending = Some(PendingSidecar {
+            child: child_process,
+            pid,
+            port: new_port,
+        });
+    }
+
+    start_pending_sidecar_monitoring(rx, Arc::clone(sidecar_state), pid, old_port, new_port, app_handle.clone(), spec);
+    Ok(())
+}
+
+/// Watches a [`PendingSidecar`] spawned by [`restart_sidecar_blue_green`] in the background,
+/// then either promotes it to active once it's healthy or discards it if it dies or never comes
+/// up within [`BLUE_GREEN_HEALTH_TIMEOUT_SECS`].
+///
+/// The same `rx` carries events both before and after promotion, since it's still the one
+/// `CommandChild` the whole time - only what we do with those events changes. Before promotion
+/// its stdout is discarded and a `Terminated` event means the replacement never made it, so we
+/// discard it. After [`promote_pending_sidecar`] flips `state.pid` to this PID, stdout control
+/// frames are decoded for real and a `Terminated` event goes through [`handle_terminated_event`]
+/// like any other active sidecar, so restart/backoff/crash-budget supervision keeps working
+/// across the swap.
+fn start_pending_sidecar_monitoring(
+    mut rx: tauri::async_runtime::Receiver<CommandEvent>,
+    sidecar_state: SidecarChild,
+    pid: u32,
+    old_port: u16,
+    new_port: u16,
+    app_handle: tauri::AppHandle,
+    spec: SidecarSpec,
+) {
+    let health_state = Arc::clone(&sidecar_state);
+    std::thread::spawn(move || {
+        if wait_for_backend_ready_on(new_port, Duration::from_secs(BLUE_GREEN_HEALTH_TIMEOUT_SECS)) {
+            promote_pending_sidecar(&health_state, old_port, new_port);
+        } else {
+            warn!("Replacement sidecar on port {} never became healthy, discarding it", new_port);
+            discard_pending_sidecar(&health_state, pid);
+        }
+    });
+
+    start_exit_reaper(Arc::clone(&sidecar_state), pid, app_handle.clone(), spec);
+
+    tauri::async_runtime::spawn(async move {
+        let mut control_frames = FrameDecoder::new();
+
+        while let Some(event) = rx.recv().await {
+            let promoted = matches!(sidecar_state.lock(), Ok(state) if state.pid == Some(pid));
+            match event {
+                CommandEvent::Stdout(bytes) => {
+                    if promoted {
+                        handle_stdout_event(bytes, &mut control_frames, &sidecar_state);
+                    }
+                }
+                CommandEvent::Stderr(bytes) => handle_stderr_event(bytes),
+                CommandEvent::Error(err) => error!("sidecar error (PID: {}): {}", pid, err),
+                CommandEvent::Terminated(payload) => {
+                    if promoted {
+                        if let RestartDecision::Restart(backoff) =
+                            handle_terminated_event(payload, &sidecar_state, pid)
+                        {
+                            schedule_restart(app_handle.clone(), Arc::clone(&sidecar_state), backoff, spec);
+                        }
+                    } else {
+                        warn!("Pending sidecar (PID: {}) terminated before promotion: {:?}", pid, payload.code);
+                        discard_pending_sidecar(&sidecar_state, pid);
+                    }
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+}
+
+/// Atomically swaps a promoted pending sidecar into the active slot and retires the old one.
+/// No-op if the pending sidecar was already discarded (e.g. it crashed) or doesn't match
+/// `new_port` (a newer restart superseded it).
+fn promote_pending_sidecar(sidecar_state: &SidecarChild, old_port: u16, new_port: u16) {
+    let (old_child, pending) = {
+        let mut state = match sidecar_state.lock() {
+            Ok(state) => state,
+            Err(e) => {
+                error!("Failed to lock sidecar state while promoting replacement sidecar: {}", e);
+                return;
+            }
+        };
+
+        let Some(pending) = state.pending.take() else {
+            debug!("Pending sidecar already resolved, nothing to promote");
+            return;
+        };
+
+        if pending.port != new_port {
+            state.pending = Some(pending);
+            return;
+        }
+
+        let old_child = state.child.take();
+        state.child = Some(pending.child);
+        state.pid = Some(pending.pid);
+        state.spawn_time = Some(Instant::now());
+        state.is_ready = true;
+        state.active_port = new_port;
+        (old_child, pending)
+    };
+
+    info!(
+        "Promoted replacement sidecar (PID: {}) to active on port {}, retiring old sidecar on port {}",
+        pending.pid, new_port, old_port
+    );
+
+    if let Some(child) = old_child {
+        if !graceful_stop_sidecar(child.pid()) {
+            let _ = child.kill();
+        }
+    }
+}
+
+/// Discards a pending sidecar that died or never came up healthy, without touching the
+/// still-active one.
+fn discard_pending_sidecar(sidecar_state: &SidecarChild, pid: u32) {
+    let mut state = match sidecar_state.lock() {
+        Ok(state) => state,
+        Err(e) => {
+            error!("Failed to lock sidecar state while discarding replacement sidecar: {}", e);
+            return;
+        }
+    };
+
+    match &state.pending {
+        Some(pending) if pending.pid == pid => state.pending = None,
+        _ => return,
+    }
+    drop(state);
+    kill_process_tree(pid);
+}
+
+/// Returns the port the frontend should currently talk to for the named sidecar.
+#[tauri::command]
+fn active_backend_port(name: String, registry: tauri::State<SidecarRegistry>) -> Result<u16, String> {
+    let sidecar_state = registry.get(&name).ok_or_else(|| format!("Sidecar '{}' is not running", name))?;
+    Ok(active_port(&sidecar_state))
+}
+
+/// Triggers a zero-downtime blue-green restart of the named backend sidecar.
+#[tauri::command]
+fn restart_backend(
+    name: String,
+    app_handle: tauri::AppHandle,
+    registry: tauri::State<SidecarRegistry>,
+) -> Result<(), String> {
+    let spec = find_sidecar_spec(&name).ok_or_else(|| format!("Unknown sidecar '{}'", name))?;
+    let sidecar_state = registry.entry(&name);
+    restart_sidecar_blue_green(&app_handle, &sidecar_state, spec).map_err(|e| e.to_string())
+}
+
+/// Starts the named sidecar if it isn't already running.
+#[tauri::command]
+fn start_sidecar(
+    name: String,
+    app_handle: tauri::AppHandle,
+    registry: tauri::State<SidecarRegistry>,
+) -> Result<(), String> {
+    let spec = find_sidecar_spec(&name).ok_or_else(|| format!("Unknown sidecar '{}'", name))?;
+    let sidecar_state = registry.entry(&name);
+    reset_sidecar_for_restart(&sidecar_state).map_err(|e| e.to_string())?;
+    spawn_sidecar_process(&app_handle, &sidecar_state, spec, spec.port).map_err(|e| e.to_string())
+}
+
+/// Clears whatever a previous [`stop_sidecar`] call or a crash-budget giveup left behind, so a
+/// sidecar that was explicitly stopped or that gave up after too many crashes can be started
+/// again by name instead of `should_spawn_sidecar` silently refusing forever.
+fn reset_sidecar_for_restart(sidecar_state: &SidecarChild) -> Result<(), Box<dyn std::error::Error>> {
+    let mut state = sidecar_state
+        .lock()
+        .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+    state.should_run = true;
+    state.failed = false;
+    state.consecutive_restarts = 0;
+    state.restart_attempts.clear();
+    Ok(())
+}
+
+/// Stops the named sidecar, if it's running.
+#[tauri::command]
+fn stop_sidecar(name: String, registry: tauri::State<SidecarRegistry>) -> Result<(), String> {
+    let Some(sidecar_state) = registry.get(&name) else {
+        return Ok(());
+    };
+
+    let mut state = sidecar_state
+        .lock()
+        .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+    state.should_run = false;
+
+    if let Some(child) = state.child.take() {
+        let pid = child.pid();
+        drop(state);
+        if !graceful_stop_sidecar(pid) {
+            let _ = child.kill();
+        }
+    }
+
+    Ok(())
+}
+
+/// Reports whether the named sidecar is running and ready, for the frontend to poll.
+#[tauri::command]
+fn sidecar_status(name: String, registry: tauri::State<SidecarRegistry>) -> Result<SidecarStatus, String> {
+    let sidecar_state = registry.get(&name).ok_or_else(|| format!("Unknown sidecar '{}'", name))?;
+    let state = sidecar_state
+        .lock()
+        .map_err(|e| format!("Failed to lock sidecar state: {}", e))?;
+
+    Ok(SidecarStatus {
+        running: state.pid.is_some(),
+        ready: state.is_ready,
+        pid: state.pid,
+        port: state.active_port,
+        failed: state.failed,
+    })
+}
+
+/// Sends `request` to the named sidecar over its control channel and returns the reply.
+#[tauri::command]
+fn sidecar_request(
+    name: String,
+    request: serde_json::Value,
+    registry: tauri::State<SidecarRegistry>,
+) -> Result<serde_json::Value, String> {
+    let sidecar_state = registry.get(&name).ok_or_else(|| format!("Sidecar '{}' is not running", name))?;
+    send_sidecar_request(&sidecar_state, request).map_err(|e| e.to_string())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     let sidecar_state: SidecarChild = Arc::new(Mutex::new(SidecarState::new()));
+    let sidecar_registry = SidecarRegistry::new();
+    sidecar_registry.insert(MAIN_SIDECAR.name, sidecar_state.clone());
 
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_cli::init())
         .plugin(tauri_plugin_opener::init())
-        .manage(sidecar_state.clone())
-        .invoke_handler(tauri::generate_handler![greet])
+        .manage(sidecar_registry)
+        .invoke_handler(tauri::generate_handler![
+            greet,
+            active_backend_port,
+            restart_backend,
+            start_sidecar,
+            stop_sidecar,
+            sidecar_status,
+            sidecar_request
+        ])
         .setup(move |app| {
             info!("Initializing application");
 
             let app_handle = app.handle().clone();
             let sidecar_for_setup = sidecar_state.clone();
 
-            if let Err(e) = spawn_sidecar_process(&app_handle, &sidecar_for_setup) {
+            if let Err(e) = spawn_sidecar_process(&app_handle, &sidecar_for_setup, MAIN_SIDECAR, BACKEND_PORT) {
                 error!("Failed to spawn sidecar: {}", e);
             }
 
@@ -355,14 +1052,19 @@ pub fn run() {
                     info!("Application shutting down, stopping sidecar");
                     state.should_run = false;
 
+                    if let Some(pending) = state.pending.take() {
+                        if !graceful_stop_sidecar(pending.pid) {
+                            let _ = pending.child.kill();
+                        }
+                    }
+
                     if let Some(child) = state.child.take() {
                         let pid = child.pid();
-                        info!("Terminating sidecar process tree (PID: {})", pid);
 
-                        kill_process_tree(pid);
-
-                        if let Err(e) = child.kill() {
-                            error!("Failed to kill sidecar: {}", e);
+                        if !graceful_stop_sidecar(pid) {
+                            if let Err(e) = child.kill() {
+                                error!("Failed to kill sidecar: {}", e);
+                            }
                         }
 
                         state.is_ready = false;
@@ -410,6 +1112,9 @@ mod tests {
         assert!(state.pid.is_none());
         assert!(state.spawn_time.is_none());
         assert!(state.should_run);
+        assert_eq!(state.consecutive_restarts, 0);
+        assert!(state.restart_attempts.is_empty());
+        assert!(!state.failed);
     }
 
     #[test]
@@ -446,6 +1151,13 @@ mod tests {
             pid: None,
             spawn_time: None,
             should_run: false,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
         }));
 
         let result = should_spawn_sidecar(&state).unwrap();
@@ -460,6 +1172,13 @@ mod tests {
             pid: None,
             spawn_time: Some(Instant::now()),
             should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
         }));
 
         let result = should_spawn_sidecar(&state).unwrap();
@@ -474,6 +1193,13 @@ mod tests {
             pid: None,
             spawn_time: None,
             should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
         }));
 
         let result = should_spawn_sidecar(&state).unwrap();
@@ -482,35 +1208,149 @@ mod tests {
 
     #[test]
     fn test_handle_stdout_event_empty() {
-        handle_stdout_event(vec![]);
+        let mut frames = FrameDecoder::new();
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        handle_stdout_event(vec![], &mut frames, &state);
     }
 
     #[test]
-    fn test_handle_stdout_event_with_content() {
-        handle_stdout_event(b"test output".to_vec());
+    fn test_handle_stdout_event_ready_frame_marks_backend_ready() {
+        let mut frames = FrameDecoder::new();
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+
+        let payload = br#"{"type":"ready"}"#;
+        let mut frame = (payload.len() as u32).to_be_bytes().to_vec();
+        frame.extend_from_slice(payload);
+
+        handle_stdout_event(frame, &mut frames, &state);
+        assert!(state.lock().unwrap().is_ready);
     }
 
     #[test]
-    fn test_handle_stderr_event_startup_complete() {
-        let state = Arc::new(Mutex::new(SidecarState::new()));
-        let result = handle_stderr_event(
-            b"Application startup complete".to_vec(),
-            false,
-            &state,
+    fn test_handle_stderr_event_duplicate_instance() {
+        handle_stderr_event(b"Another instance is already running".to_vec());
+    }
+
+    #[test]
+    fn test_frame_decoder_waits_for_complete_frame() {
+        let mut decoder = FrameDecoder::new();
+        assert!(decoder.push(&[0, 0, 0, 5]).is_empty());
+        assert!(decoder.push(&[b'h', b'e']).is_empty());
+        assert_eq!(decoder.push(&[b'l', b'l', b'o']), vec![b"hello".to_vec()]);
+    }
+
+    #[test]
+    fn test_frame_decoder_extracts_multiple_frames_from_one_push() {
+        let mut decoder = FrameDecoder::new();
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&3u32.to_be_bytes());
+        bytes.extend_from_slice(b"foo");
+        bytes.extend_from_slice(&3u32.to_be_bytes());
+        bytes.extend_from_slice(b"bar");
+
+        let frames = decoder.push(&bytes);
+        assert_eq!(frames, vec![b"foo".to_vec(), b"bar".to_vec()]);
+    }
+
+    #[test]
+    fn test_parse_sidecar_event_ready() {
+        assert_eq!(parse_sidecar_event(br#"{"type":"ready"}"#), Some(SidecarEvent::Ready));
+    }
+
+    #[test]
+    fn test_parse_sidecar_event_health() {
+        assert_eq!(
+            parse_sidecar_event(br#"{"type":"health","status":"healthy"}"#),
+            Some(SidecarEvent::Health { healthy: true })
+        );
+        assert_eq!(
+            parse_sidecar_event(br#"{"type":"health","status":"unhealthy"}"#),
+            Some(SidecarEvent::Health { healthy: false })
         );
-        assert!(result);
-        assert!(state.lock().unwrap().is_ready);
     }
 
     #[test]
-    fn test_handle_stderr_event_duplicate_instance() {
-        let state = Arc::new(Mutex::new(SidecarState::new()));
-        let result = handle_stderr_event(
-            b"Another instance is already running".to_vec(),
-            false,
-            &state,
+    fn test_parse_sidecar_event_shutting_down() {
+        assert_eq!(parse_sidecar_event(br#"{"type":"shutting_down"}"#), Some(SidecarEvent::ShuttingDown));
+    }
+
+    #[test]
+    fn test_parse_sidecar_event_reply() {
+        assert_eq!(
+            parse_sidecar_event(br#"{"type":"reply","id":7,"payload":{"ok":true}}"#),
+            Some(SidecarEvent::Reply { id: 7, payload: serde_json::json!({"ok": true}) })
         );
-        assert!(!result);
+    }
+
+    #[test]
+    fn test_parse_sidecar_event_unknown_type_is_none() {
+        assert_eq!(parse_sidecar_event(br#"{"type":"mystery"}"#), None);
+    }
+
+    #[test]
+    fn test_parse_sidecar_event_malformed_json_is_none() {
+        assert_eq!(parse_sidecar_event(b"not json"), None);
+    }
+
+    #[test]
+    fn test_apply_sidecar_event_shutting_down_clears_ready() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        state.lock().unwrap().is_ready = true;
+
+        apply_sidecar_event(SidecarEvent::ShuttingDown, &state);
+
+        assert!(!state.lock().unwrap().is_ready);
+    }
+
+    #[test]
+    fn test_apply_sidecar_event_reply_delivers_to_pending_sender() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        let (tx, rx) = mpsc::channel();
+        state.lock().unwrap().pending_replies.insert(7, tx);
+
+        apply_sidecar_event(SidecarEvent::Reply { id: 7, payload: serde_json::json!({"ok": true}) }, &state);
+
+        assert_eq!(rx.recv().unwrap(), serde_json::json!({"ok": true}));
+        assert!(state.lock().unwrap().pending_replies.is_empty());
+    }
+
+    #[test]
+    fn test_apply_sidecar_event_reply_for_unknown_id_is_noop() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+
+        apply_sidecar_event(SidecarEvent::Reply { id: 99, payload: serde_json::Value::Null }, &state);
+
+        assert!(state.lock().unwrap().pending_replies.is_empty());
+    }
+
+    #[test]
+    fn test_find_sidecar_spec() {
+        assert_eq!(find_sidecar_spec("main"), Some(MAIN_SIDECAR));
+        assert_eq!(find_sidecar_spec("nonexistent"), None);
+    }
+
+    #[test]
+    fn test_sidecar_registry_entry_is_stable_across_calls() {
+        let registry = SidecarRegistry::new();
+
+        let first = registry.entry("main");
+        let second = registry.entry("main");
+
+        assert!(Arc::ptr_eq(&first, &second));
+    }
+
+    #[test]
+    fn test_sidecar_registry_insert_overrides_entry() {
+        let registry = SidecarRegistry::new();
+        let original = registry.entry("main");
+        let seeded = Arc::new(Mutex::new(SidecarState::new()));
+        registry.insert("main", seeded.clone());
+
+        let fetched = registry.get("main").expect("main was just inserted");
+
+        assert!(Arc::ptr_eq(&seeded, &fetched));
+        assert!(!Arc::ptr_eq(&original, &fetched));
+        assert!(registry.get("unknown").is_none());
     }
 
     #[test]
@@ -521,6 +1361,13 @@ mod tests {
             pid: Some(12345),
             spawn_time: Some(Instant::now()),
             should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
         }));
 
         let payload = tauri_plugin_shell::process::TerminatedPayload {
@@ -528,28 +1375,158 @@ mod tests {
             signal: None,
         };
 
-        handle_terminated_event(payload, &state, 12345);
+        let decision = handle_terminated_event(payload, &state, 12345);
+
+        assert_eq!(decision, RestartDecision::Restart(Duration::from_secs(RESTART_BACKOFF_BASE_SECS)));
 
         let locked_state = state.lock().unwrap();
         assert!(locked_state.child.is_none());
         assert!(!locked_state.is_ready);
         assert!(locked_state.pid.is_none());
         assert!(locked_state.spawn_time.is_none());
+        assert_eq!(locked_state.consecutive_restarts, 1);
+    }
+
+    #[test]
+    fn test_handle_terminated_event_stale_pid_ignored() {
+        let state = Arc::new(Mutex::new(SidecarState {
+            child: None,
+            is_ready: true,
+            pid: Some(12345),
+            spawn_time: Some(Instant::now()),
+            should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
+        }));
+
+        let payload = tauri_plugin_shell::process::TerminatedPayload {
+            code: Some(0),
+            signal: None,
+        };
+
+        let decision = handle_terminated_event(payload, &state, 999);
+
+        assert_eq!(decision, RestartDecision::Stopped);
+        assert!(state.lock().unwrap().pid.is_some());
+    }
+
+    #[test]
+    fn test_handle_terminated_event_no_restart_when_shutting_down() {
+        let state = Arc::new(Mutex::new(SidecarState {
+            child: None,
+            is_ready: true,
+            pid: Some(12345),
+            spawn_time: Some(Instant::now()),
+            should_run: false,
+            consecutive_restarts: 0,
+            restart_attempts: Vec::new(),
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
+        }));
+
+        let payload = tauri_plugin_shell::process::TerminatedPayload {
+            code: Some(0),
+            signal: None,
+        };
+
+        let decision = handle_terminated_event(payload, &state, 12345);
+
+        assert_eq!(decision, RestartDecision::Stopped);
+        assert!(!state.lock().unwrap().failed);
+    }
+
+    #[test]
+    fn test_handle_terminated_event_exceeding_crash_budget_marks_failed() {
+        let now = Instant::now();
+        let state = Arc::new(Mutex::new(SidecarState {
+            child: None,
+            is_ready: true,
+            pid: Some(12345),
+            spawn_time: Some(now),
+            should_run: true,
+            consecutive_restarts: 0,
+            restart_attempts: vec![now; CRASH_BUDGET_MAX_RESTARTS],
+            failed: false,
+            active_port: BACKEND_PORT,
+            pending: None,
+            pending_replies: HashMap::new(),
+            next_request_id: 0,
+        }));
+
+        let payload = tauri_plugin_shell::process::TerminatedPayload {
+            code: Some(1),
+            signal: None,
+        };
+
+        let decision = handle_terminated_event(payload, &state, 12345);
+
+        assert_eq!(decision, RestartDecision::CrashBudgetExceeded);
+        let locked_state = state.lock().unwrap();
+        assert!(locked_state.failed);
+        assert!(!locked_state.should_run);
     }
 
     #[test]
     fn test_is_backend_already_ready_not_healthy() {
         let state = Arc::new(Mutex::new(SidecarState::new()));
-        let result = is_backend_already_ready(&state).unwrap();
+        let result = is_backend_already_ready(&state, BACKEND_PORT).unwrap();
         assert!(!result);
     }
 
     #[test]
     fn test_prepare_backend_environment_port_available() {
-        let result = prepare_backend_environment();
+        let result = prepare_backend_environment(BACKEND_PORT);
         assert!(result.is_ok());
     }
 
+    #[test]
+    #[serial]
+    fn test_graceful_stop_sidecar_nonexistent_pid() {
+        assert!(graceful_stop_sidecar(999999));
+    }
+
+    #[test]
+    fn test_alternate_port_picks_the_other_pool_member() {
+        assert_eq!(alternate_port(BACKEND_PORT_POOL[0]), BACKEND_PORT_POOL[1]);
+        assert_eq!(alternate_port(BACKEND_PORT_POOL[1]), BACKEND_PORT_POOL[0]);
+    }
+
+    #[test]
+    fn test_alternate_port_picks_a_pool_port_when_current_not_in_pool() {
+        assert_eq!(alternate_port(9999), BACKEND_PORT_POOL[0]);
+    }
+
+    #[test]
+    fn test_active_port_reflects_state() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        assert_eq!(active_port(&state), BACKEND_PORT);
+
+        state.lock().unwrap().active_port = BACKEND_PORT_POOL[1];
+        assert_eq!(active_port(&state), BACKEND_PORT_POOL[1]);
+    }
+
+    #[test]
+    fn test_discard_pending_sidecar_no_pending_is_noop() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        discard_pending_sidecar(&state, 111);
+        assert!(state.lock().unwrap().pending.is_none());
+    }
+
+    #[test]
+    fn test_promote_pending_sidecar_no_pending_is_noop() {
+        let state = Arc::new(Mutex::new(SidecarState::new()));
+        promote_pending_sidecar(&state, BACKEND_PORT, BACKEND_PORT_POOL[1]);
+        assert_eq!(state.lock().unwrap().active_port, BACKEND_PORT);
+    }
+
     mod sidecar_state_tests {
         use super::*;
 
@@ -561,6 +1538,9 @@ mod tests {
             assert!(state.pid.is_none());
             assert!(state.spawn_time.is_none());
             assert!(state.should_run);
+            assert_eq!(state.consecutive_restarts, 0);
+            assert!(state.restart_attempts.is_empty());
+            assert!(!state.failed);
         }
 
         #[test]
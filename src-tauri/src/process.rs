@@ -1,89 +1,688 @@
-use std::process::Command;
 use std::time::Duration;
-use log::{debug, info};
+use log::{debug, info, warn};
 
 use crate::PROCESS_KILL_DELAY_MS;
 
-#[cfg(target_os = "windows")]
+#[cfg(feature = "shell-fallback")]
+use std::process::Command;
+
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+use libc::pid_t;
+
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+mod pidfd {
+    use std::os::unix::io::RawFd;
+
+    // Not yet exposed by `libc` on all toolchains we target, so the raw syscall numbers
+    // are used directly (the same on x86_64 and aarch64; pidfd_open since Linux 5.3,
+    // pidfd_send_signal since 5.1).
+    const SYS_PIDFD_OPEN: libc::c_long = 434;
+    const SYS_PIDFD_SEND_SIGNAL: libc::c_long = 424;
+
+    /// A pidfd pins down the exact process it was opened for, so signalling it can never
+    /// land on a PID that the kernel recycled onto an unrelated process in the meantime.
+    pub struct Pidfd(RawFd);
+
+    impl Pidfd {
+        /// Opens a pidfd for `pid`. Returns `None` on `ENOSYS` (Linux < 5.3) or if the
+        /// process has already exited, so callers can fall back to PID-based signalling.
+        pub fn open(pid: u32) -> Option<Self> {
+            let fd = unsafe { libc::syscall(SYS_PIDFD_OPEN, pid as libc::pid_t, 0) };
+            if fd < 0 {
+                None
+            } else {
+                Some(Self(fd as RawFd))
+            }
+        }
+
+        pub fn send_signal(&self, signal: libc::c_int) -> bool {
+            let rc = unsafe {
+                libc::syscall(SYS_PIDFD_SEND_SIGNAL, self.0, signal, std::ptr::null::<()>(), 0)
+            };
+            rc == 0
+        }
+
+        /// Non-blocking check of whether the process is still alive: the pidfd becomes
+        /// readable once the kernel has reaped it (or it's ready to be reaped).
+        pub fn has_exited(&self) -> bool {
+            let mut pfd = libc::pollfd {
+                fd: self.0,
+                events: libc::POLLIN,
+                revents: 0,
+            };
+            let ready = unsafe { libc::poll(&mut pfd, 1, 0) };
+            ready > 0 && (pfd.revents & libc::POLLIN) != 0
+        }
+    }
+
+    impl Drop for Pidfd {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.0);
+            }
+        }
+    }
+
+    impl std::os::unix::io::AsRawFd for Pidfd {
+        fn as_raw_fd(&self) -> RawFd {
+            self.0
+        }
+    }
+}
+
+#[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+mod win_native {
+    use windows_sys::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows_sys::Win32::NetworkManagement::IpHelper::{
+        GetExtendedTcpTable, MIB_TCPROW_OWNER_PID, MIB_TCPTABLE_OWNER_PID, TCP_TABLE_OWNER_PID_ALL,
+    };
+    use windows_sys::Win32::Networking::WinSock::AF_INET;
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+        TH32CS_SNAPPROCESS,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenProcess, TerminateProcess, PROCESS_TERMINATE,
+    };
+
+    /// Enumerates the system TCP table via `GetExtendedTcpTable` and returns the PIDs of
+    /// whatever process owns `port` as its local port. Unlike the `netstat -ano` scan of the
+    /// shell-fallback path, which keeps only `LISTENING` rows, this matches sockets in any
+    /// TCP state.
+    pub fn pids_holding_port(port: u16) -> Vec<u32> {
+        let mut pids = Vec::new();
+
+        unsafe {
+            let mut size: u32 = 0;
+            // First call with an empty buffer just to learn the required size.
+            GetExtendedTcpTable(std::ptr::null_mut(), &mut size, 0, AF_INET as u32, TCP_TABLE_OWNER_PID_ALL, 0);
+            if size == 0 {
+                return pids;
+            }
+
+            let mut buffer = vec![0u8; size as usize];
+            let result = GetExtendedTcpTable(
+                buffer.as_mut_ptr() as *mut _,
+                &mut size,
+                0,
+                AF_INET as u32,
+                TCP_TABLE_OWNER_PID_ALL,
+                0,
+            );
+            if result != 0 {
+                return pids;
+            }
+
+            let table = buffer.as_ptr() as *const MIB_TCPTABLE_OWNER_PID;
+            let num_entries = (*table).dwNumEntries as usize;
+            let rows = (*table).table.as_ptr() as *const MIB_TCPROW_OWNER_PID;
+            for i in 0..num_entries {
+                let row = &*rows.add(i);
+                // `dwLocalPort` holds the port in network byte order in its low 16 bits,
+                // same as the `ntohs((u_short)row.dwLocalPort)` idiom used in C.
+                let local_port = u16::from_be(row.dwLocalPort as u16);
+                if local_port == port {
+                    pids.push(row.dwOwningPid);
+                }
+            }
+        }
+
+        pids
+    }
+
+    pub fn children_of(pid: u32) -> Vec<u32> {
+        let mut children = Vec::new();
+        unsafe {
+            let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0);
+            if snapshot == -1isize as HANDLE {
+                return children;
+            }
+
+            let mut entry: PROCESSENTRY32W = std::mem::zeroed();
+            entry.dwSize = std::mem::size_of::<PROCESSENTRY32W>() as u32;
+
+            if Process32FirstW(snapshot, &mut entry) != 0 {
+                loop {
+                    if entry.th32ParentProcessID == pid {
+                        children.push(entry.th32ProcessID);
+                    }
+                    if Process32NextW(snapshot, &mut entry) == 0 {
+                        break;
+                    }
+                }
+            }
+
+            CloseHandle(snapshot);
+        }
+        children
+    }
+
+    pub fn terminate(pid: u32) -> bool {
+        unsafe {
+            let handle = OpenProcess(PROCESS_TERMINATE, 0, pid);
+            if handle.is_null() {
+                return false;
+            }
+            let ok = TerminateProcess(handle, 1) != 0;
+            CloseHandle(handle);
+            ok
+        }
+    }
+
+    /// Non-final escalation steps map to a graceful close rather than a forced kill.
+    /// `GenerateConsoleCtrlEvent` only reaches processes attached to our console's process
+    /// group, which covers the common case of a console sidecar we spawned directly; a
+    /// `WM_CLOSE`-based path for windowed processes is a native TODO alongside the other
+    /// platform gaps noted in this file.
+    pub fn request_graceful_close(pid: u32) -> bool {
+        unsafe {
+            windows_sys::Win32::System::Console::GenerateConsoleCtrlEvent(
+                windows_sys::Win32::System::Console::CTRL_BREAK_EVENT,
+                pid,
+            ) != 0
+        }
+    }
+
+    pub fn is_running(pid: u32) -> bool {
+        unsafe {
+            let handle = OpenProcess(windows_sys::Win32::System::Threading::PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
+            if handle.is_null() {
+                return false;
+            }
+            let mut exit_code: u32 = 0;
+            let ok = windows_sys::Win32::System::Threading::GetExitCodeProcess(handle, &mut exit_code) != 0;
+            CloseHandle(handle);
+            ok && exit_code == windows_sys::Win32::Foundation::STILL_ACTIVE as u32
+        }
+    }
+}
+
+#[cfg(all(target_os = "macos", not(feature = "shell-fallback")))]
+mod kqueue {
+    use std::os::unix::io::{AsRawFd, RawFd};
+
+    /// A one-shot `EVFILT_PROC`/`NOTE_EXIT` watch on a single PID, hand-rolled like the
+    /// Linux [`super::pidfd::Pidfd`] above rather than pulling in a general-purpose kqueue
+    /// crate: the kernel fires this the moment the watched process exits, which is what
+    /// lets the async reaper await a real wakeup instead of polling.
+    pub struct ProcWatch(RawFd);
+
+    impl ProcWatch {
+        /// Registers interest in `pid`'s exit. Returns `None` if `kqueue()` fails or the
+        /// process is already gone, so callers can fall back to the SIGCHLD-based reaper.
+        pub fn register(pid: u32) -> Option<Self> {
+            let kq = unsafe { libc::kqueue() };
+            if kq < 0 {
+                return None;
+            }
+
+            let change = libc::kevent {
+                ident: pid as usize,
+                filter: libc::EVFILT_PROC,
+                flags: libc::EV_ADD | libc::EV_ENABLE | libc::EV_ONESHOT,
+                fflags: libc::NOTE_EXIT,
+                data: 0,
+                udata: std::ptr::null_mut(),
+            };
+
+            let rc = unsafe { libc::kevent(kq, &change, 1, std::ptr::null_mut(), 0, std::ptr::null()) };
+            if rc < 0 {
+                unsafe { libc::close(kq) };
+                return None;
+            }
+
+            Some(Self(kq))
+        }
+    }
+
+    impl Drop for ProcWatch {
+        fn drop(&mut self) {
+            unsafe {
+                libc::close(self.0);
+            }
+        }
+    }
+
+    impl AsRawFd for ProcWatch {
+        fn as_raw_fd(&self) -> RawFd {
+            self.0
+        }
+    }
+}
+
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+mod signal_reaper {
+    use std::os::unix::io::AsRawFd;
+    use std::os::unix::net::UnixStream;
+    use std::sync::OnceLock;
+
+    /// One process-wide SIGCHLD subscription, shared by every in-flight async wait that
+    /// doesn't have a per-process kernel handle to watch (pidfd on Linux, kqueue
+    /// `EVFILT_PROC` on macOS — this is the "older kernels and other Unixes" fallback):
+    /// `signal_hook` writes a byte to this pipe's write half on every SIGCHLD delivered to
+    /// the process, and each waiter wakes, re-checks its own PID, and calls
+    /// [`next_sigchld`] again if it wasn't theirs. Lazily registered once and kept for the
+    /// process's lifetime — signal handlers are inherently global, so there's nothing
+    /// meaningful to unregister.
+    fn pipe() -> &'static async_io::Async<UnixStream> {
+        static PIPE: OnceLock<async_io::Async<UnixStream>> = OnceLock::new();
+        PIPE.get_or_init(|| {
+            let (read, write) = UnixStream::pair().expect("unix socketpair creation is infallible in practice");
+            signal_hook::low_level::pipe::register(signal_hook::consts::SIGCHLD, write)
+                .expect("registering a SIGCHLD handler is infallible in practice");
+            async_io::Async::new(read).expect("registering the SIGCHLD pipe with the reactor is infallible in practice")
+        })
+    }
+
+    /// Waits for the next SIGCHLD delivered to this process, draining the bytes
+    /// `signal_hook` wrote so the following call blocks again instead of returning
+    /// immediately on stale readiness.
+    pub async fn next_sigchld() {
+        let pipe = pipe();
+        let _ = pipe.readable().await;
+
+        let fd = pipe.as_raw_fd();
+        let mut buf = [0u8; 64];
+        loop {
+            let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+            if n <= 0 {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "shell-fallback")]
 mod commands {
+    #[cfg(target_os = "windows")]
     pub const KILL_TREE: &str = "taskkill";
+    #[cfg(target_os = "windows")]
     pub const KILL_TREE_ARGS: &[&str] = &["/F", "/T", "/PID"];
+    #[cfg(target_os = "windows")]
     pub const CHECK_PROCESS: &str = "tasklist";
+    #[cfg(target_os = "windows")]
     pub const CHECK_PROCESS_ARGS: &[&str] = &["/FI"];
+    #[cfg(target_os = "windows")]
     pub const NETSTAT: &str = "netstat";
+    #[cfg(target_os = "windows")]
     pub const NETSTAT_ARGS: &[&str] = &["-ano", "-p", "tcp"];
+    #[cfg(target_os = "windows")]
     pub const FORCE_KILL: &str = "taskkill";
+    #[cfg(target_os = "windows")]
     pub const FORCE_KILL_ARGS: &[&str] = &["/F", "/PID"];
-}
+    #[cfg(target_os = "windows")]
+    pub const GRACEFUL_KILL: &str = "taskkill";
+    #[cfg(target_os = "windows")]
+    pub const GRACEFUL_KILL_ARGS: &[&str] = &["/PID"];
 
-#[cfg(target_os = "macos")]
-mod commands {
+    #[cfg(target_os = "macos")]
     pub const FIND_CHILDREN: &str = "pgrep";
+    #[cfg(target_os = "macos")]
     pub const FIND_CHILDREN_ARGS: &[&str] = &["-P"];
-    pub const KILL_TERM: &str = "kill";
-    pub const KILL_FORCE: &str = "kill";
-    pub const TERM_SIGNAL: &str = "-TERM";
-    pub const KILL_SIGNAL: &str = "-KILL";
-    pub const CHECK_SIGNAL: &str = "-0";
+    #[cfg(target_os = "macos")]
     pub const LSOF: &str = "lsof";
+    #[cfg(target_os = "macos")]
     pub const LSOF_ARGS: &[&str] = &["-ti"];
-}
 
-#[cfg(target_os = "linux")]
-mod commands {
-    pub const KILL_CHILDREN: &str = "pkill";
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub const KILL_TERM: &str = "kill";
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub const KILL_FORCE: &str = "kill";
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub const TERM_SIGNAL: &str = "-TERM";
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub const KILL_SIGNAL: &str = "-KILL";
+    #[cfg(any(target_os = "macos", target_os = "linux"))]
     pub const CHECK_SIGNAL: &str = "-0";
+
+    #[cfg(target_os = "linux")]
+    pub const KILL_CHILDREN: &str = "pkill";
+    #[cfg(target_os = "linux")]
     pub const PARENT_FLAG: &str = "-P";
+    #[cfg(target_os = "linux")]
     pub const FUSER: &str = "fuser";
+    #[cfg(target_os = "linux")]
     pub const FUSER_KILL_ARGS: &[&str] = &["-k"];
+    #[cfg(target_os = "linux")]
     pub const FUSER_TERM_ARGS: &[&str] = &["-k", "-TERM"];
 }
 
-#[derive(Debug)]
 struct TerminationStrategy {
-    graceful_cmd: String,
-    graceful_args: Vec<String>,
-    force_cmd: String,
-    force_args: Vec<String>,
+    pid: u32,
+    #[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+    pidfd: Option<pidfd::Pidfd>,
 }
 
-impl TerminationStrategy {
-    fn execute(&self) {
-        debug!("Executing graceful termination: {} {:?}", self.graceful_cmd, self.graceful_args);
-        let _ = Command::new(&self.graceful_cmd).args(&self.graceful_args).output();
+impl std::fmt::Debug for TerminationStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TerminationStrategy").field("pid", &self.pid).finish()
+    }
+}
 
-        std::thread::sleep(Duration::from_millis(PROCESS_KILL_DELAY_MS));
+/// How a [`TerminationStrategy`] actually played out, modeled loosely on
+/// `std::os::unix::process::ExitStatusExt`'s signal reporting: callers get to see whether
+/// the graceful signal was enough, or whether we had to escalate to a kill.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TerminationOutcome {
+    pub exited_gracefully: bool,
+    pub signal: Option<i32>,
+    pub exit_code: Option<i32>,
+    pub escalated: bool,
+}
+
+/// How a process that we were merely watching (not terminating) went away, so callers like
+/// the sidecar supervisor can tell a clean exit from a crash.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExitStatus {
+    pub exit_code: Option<i32>,
+    pub signal: Option<i32>,
+}
+
+/// POSIX signal numbers, kept as plain constants (rather than pulled from `libc`) so the
+/// default ladder is available even when built against the `shell-fallback` feature, which
+/// doesn't otherwise depend on `libc`.
+pub const SIGTERM: i32 = 15;
+pub const SIGINT: i32 = 2;
+pub const SIGHUP: i32 = 1;
+pub const SIGQUIT: i32 = 3;
+pub const SIGKILL: i32 = 9;
+
+/// One rung of an [`EscalationLadder`]: send `signal`, then wait up to `wait` for the
+/// process to exit before moving on to the next rung.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EscalationStep {
+    pub signal: i32,
+    pub wait: Duration,
+}
+
+/// An ordered sequence of signals to try before giving up on a graceful exit. The last
+/// step is always the final word: on Unix it's whatever signal it says (typically
+/// `SIGKILL`), and on Windows it's always mapped to `TerminateProcess` regardless of which
+/// signal number is recorded there, since Windows has no real signal delivery.
+///
+/// ```ignore
+/// let ladder = EscalationLadder::new()
+///     .step(process::SIGINT, Duration::from_secs(2))
+///     .step(process::SIGTERM, Duration::from_secs(5))
+///     .step(process::SIGKILL, Duration::from_millis(500));
+/// ```
+#[derive(Debug, Clone)]
+pub struct EscalationLadder {
+    steps: Vec<EscalationStep>,
+}
+
+impl EscalationLadder {
+    /// Starts an empty ladder. At least one [`EscalationLadder::step`] must be added
+    /// before use; an empty ladder kills nothing.
+    pub fn new() -> Self {
+        Self { steps: Vec::new() }
+    }
 
-        debug!("Executing force termination: {} {:?}", self.force_cmd, self.force_args);
-        let _ = Command::new(&self.force_cmd).args(&self.force_args).output();
+    /// Appends a rung: send `signal`, then wait up to `wait` before trying the next one.
+    pub fn step(mut self, signal: i32, wait: Duration) -> Self {
+        self.steps.push(EscalationStep { signal, wait });
+        self
     }
 
-    #[cfg(not(target_os = "windows"))]
+    pub fn steps(&self) -> &[EscalationStep] {
+        &self.steps
+    }
+}
+
+impl Default for EscalationLadder {
+    /// SIGTERM, wait for [`crate::GRACEFUL_TERMINATION_TIMEOUT_MS`], then SIGKILL — the
+    /// ladder this module used before it became configurable.
+    fn default() -> Self {
+        Self::new()
+            .step(SIGTERM, Duration::from_millis(crate::GRACEFUL_TERMINATION_TIMEOUT_MS))
+            .step(SIGKILL, Duration::from_millis(PROCESS_KILL_DELAY_MS))
+    }
+}
+
+/// Decodes a glibc `wait()` status word without relying on the `WIFEXITED`/`WEXITSTATUS`
+/// C macros, which `libc` doesn't expose as Rust functions.
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn decode_wait_status(status: libc::c_int) -> (Option<i32>, Option<i32>) {
+    if status & 0x7f == 0 {
+        (Some((status >> 8) & 0xff), None)
+    } else {
+        (None, Some(status & 0x7f))
+    }
+}
+
+/// Best-effort exit status reap via `waitpid(..., WNOHANG)`. Only succeeds if `pid` is an
+/// actual child of this process (e.g. the sidecar); for anything else (signalled
+/// descendants, processes found via a port scan) this harmlessly returns `(None, None)`.
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn reap_exit_status(pid: u32) -> (Option<i32>, Option<i32>) {
+    let mut status: libc::c_int = 0;
+    let rc = unsafe { libc::waitpid(pid as pid_t, &mut status, libc::WNOHANG) };
+    if rc != pid as pid_t {
+        return (None, None);
+    }
+    decode_wait_status(status)
+}
+
+impl TerminationStrategy {
+    #[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
     fn for_pid(pid: u32) -> Self {
-        Self {
-            graceful_cmd: commands::KILL_TERM.to_string(),
-            graceful_args: vec![commands::TERM_SIGNAL.to_string(), pid.to_string()],
-            force_cmd: commands::KILL_FORCE.to_string(),
-            force_args: vec![commands::KILL_SIGNAL.to_string(), pid.to_string()],
-        }
+        // Opening the pidfd here, at discovery time, is what closes the PID-reuse race:
+        // from this point on signals go to this exact process, never to whatever the
+        // kernel recycles `pid` onto later.
+        Self { pid, pidfd: pidfd::Pidfd::open(pid) }
     }
 
-    #[cfg(target_os = "windows")]
+    #[cfg(not(all(target_os = "linux", not(feature = "shell-fallback"))))]
     fn for_pid(pid: u32) -> Self {
-        Self {
-            graceful_cmd: commands::FORCE_KILL.to_string(),
-            graceful_args: vec![commands::FORCE_KILL_ARGS[0].to_string(), commands::FORCE_KILL_ARGS[1].to_string(), pid.to_string()],
-            force_cmd: commands::FORCE_KILL.to_string(),
-            force_args: vec![commands::FORCE_KILL_ARGS[0].to_string(), commands::FORCE_KILL_ARGS[1].to_string(), pid.to_string()],
+        Self { pid }
+    }
+
+    /// Walks `ladder` one rung at a time: send the rung's signal, wait up to its duration,
+    /// and only move to the next rung if the process survives. The final rung is always
+    /// the end of the line, whether or not it managed to kill the process.
+    #[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+    fn execute(&self, ladder: &EscalationLadder) -> TerminationOutcome {
+        let Some(fd) = &self.pidfd else {
+            // pidfd_open returned ENOSYS (kernel older than 5.3) or the process was
+            // already gone by discovery time; fall back to kill(2) probing.
+            debug!("No pidfd for PID {}, falling back to kill(2)", self.pid);
+            return execute_via_kill_probe(self.pid, ladder);
+        };
+
+        let steps = ladder.steps();
+        for (i, step) in steps.iter().enumerate() {
+            let is_last = i + 1 == steps.len();
+            debug!("Sending signal {} to PID {} via pidfd (step {}/{})", step.signal, self.pid, i + 1, steps.len());
+            fd.send_signal(step.signal);
+
+            let exited = poll_until_exited(|| fd.has_exited(), step.wait);
+            if exited || is_last {
+                let (exit_code, _) = reap_exit_status(self.pid);
+                return TerminationOutcome {
+                    exited_gracefully: exited && i == 0,
+                    signal: Some(step.signal),
+                    exit_code,
+                    escalated: i > 0,
+                };
+            }
+        }
+
+        unreachable!("EscalationLadder::steps() is non-empty by construction")
+    }
+
+    #[cfg(all(unix, not(target_os = "linux"), not(feature = "shell-fallback")))]
+    fn execute(&self, ladder: &EscalationLadder) -> TerminationOutcome {
+        execute_via_kill_probe(self.pid, ladder)
+    }
+
+    #[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+    fn execute(&self, ladder: &EscalationLadder) -> TerminationOutcome {
+        let steps = ladder.steps();
+        for (i, step) in steps.iter().enumerate() {
+            let is_last = i + 1 == steps.len();
+
+            if is_last {
+                debug!("Terminating PID {} via TerminateProcess (step {}/{})", self.pid, i + 1, steps.len());
+                win_native::terminate(self.pid);
+                return TerminationOutcome { exited_gracefully: false, signal: Some(step.signal), exit_code: None, escalated: i > 0 };
+            }
+
+            debug!("Requesting graceful close of PID {} (step {}/{})", self.pid, i + 1, steps.len());
+            win_native::request_graceful_close(self.pid);
+            if poll_until_exited(|| !win_native::is_running(self.pid), step.wait) {
+                return TerminationOutcome { exited_gracefully: true, signal: Some(step.signal), exit_code: None, escalated: i > 0 };
+            }
+        }
+
+        unreachable!("EscalationLadder::steps() is non-empty by construction")
+    }
+
+    #[cfg(feature = "shell-fallback")]
+    fn execute(&self, ladder: &EscalationLadder) -> TerminationOutcome {
+        #[cfg(not(target_os = "windows"))]
+        {
+            let steps = ladder.steps();
+            for (i, step) in steps.iter().enumerate() {
+                let is_last = i + 1 == steps.len();
+                let cmd = if is_last { commands::KILL_FORCE } else { commands::KILL_TERM };
+                debug!("Executing {} -s {} {} (step {}/{})", cmd, step.signal, self.pid, i + 1, steps.len());
+                let _ = Command::new(cmd)
+                    .args(&["-s", &step.signal.to_string(), &self.pid.to_string()])
+                    .output();
+
+                let exited = poll_until_exited(|| !is_process_running_platform(self.pid), step.wait);
+                if exited || is_last {
+                    return TerminationOutcome { exited_gracefully: exited && i == 0, signal: Some(step.signal), exit_code: None, escalated: i > 0 };
+                }
+            }
+            unreachable!("EscalationLadder::steps() is non-empty by construction")
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            let _ = ladder;
+            debug!("Executing force termination: taskkill /F /PID {}", self.pid);
+            let _ = Command::new(commands::FORCE_KILL)
+                .args(&[commands::FORCE_KILL_ARGS[0], commands::FORCE_KILL_ARGS[1], &self.pid.to_string()])
+                .output();
+            TerminationOutcome { exited_gracefully: false, signal: None, exit_code: None, escalated: true }
+        }
+    }
+}
+
+fn poll_until_exited(mut has_exited: impl FnMut() -> bool, timeout: Duration) -> bool {
+    let start = std::time::Instant::now();
+    loop {
+        if has_exited() {
+            return true;
+        }
+        if start.elapsed() >= timeout {
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+/// Fallback termination path for platforms/kernels without pidfd: probes liveness with
+/// `kill(pid, 0)` the same way [`is_process_running`] does.
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn execute_via_kill_probe(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
+    let steps = ladder.steps();
+    for (i, step) in steps.iter().enumerate() {
+        let is_last = i + 1 == steps.len();
+        unsafe {
+            libc::kill(pid as pid_t, step.signal as libc::c_int);
+        }
+
+        let exited = poll_until_exited(|| !is_process_running_platform(pid), step.wait);
+        if exited || is_last {
+            let (exit_code, _) = reap_exit_status(pid);
+            return TerminationOutcome { exited_gracefully: exited && i == 0, signal: Some(step.signal), exit_code, escalated: i > 0 };
         }
     }
+
+    unreachable!("EscalationLadder::steps() is non-empty by construction")
+}
+
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+fn children_of_pid(pid: u32) -> Vec<u32> {
+    let mut children = Vec::new();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return children;
+    };
+
+    for entry in entries.flatten() {
+        let Ok(child_pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+
+        let stat_path = format!("/proc/{}/stat", child_pid);
+        let Ok(stat) = std::fs::read_to_string(&stat_path) else {
+            continue;
+        };
+
+        // Fields after the `(comm)` part are space-separated; ppid is the first of those.
+        if let Some(after_comm) = stat.rsplit(')').next() {
+            if let Some(ppid_str) = after_comm.split_whitespace().nth(1) {
+                if ppid_str.parse::<u32>() == Ok(pid) {
+                    children.push(child_pid);
+                }
+            }
+        }
+    }
+
+    children
+}
+
+#[cfg(all(target_os = "macos", not(feature = "shell-fallback")))]
+fn children_of_pid(pid: u32) -> Vec<u32> {
+    // libproc doesn't expose a direct "children of" call, so we list every PID on the
+    // system and filter by parent via proc_pidinfo(PROC_PIDTBSDINFO), mirroring what
+    // `ps -o ppid=` does internally.
+    let mut children = Vec::new();
+
+    let buf_size = unsafe { libproc::libproc::proc_pid::listpids(libproc::libproc::proc_pid::ProcType::ProcAllPIDS, 0, &mut []) };
+    if buf_size <= 0 {
+        return children;
+    }
+
+    let mut pids: Vec<u32> = vec![0; buf_size as usize];
+    let n = unsafe {
+        libproc::libproc::proc_pid::listpids(
+            libproc::libproc::proc_pid::ProcType::ProcAllPIDS,
+            0,
+            &mut pids,
+        )
+    };
+
+    for &candidate in pids.iter().take(n.max(0) as usize) {
+        if candidate == 0 {
+            continue;
+        }
+        if let Ok(info) = libproc::libproc::bsd_info::BSDInfo::from_pid(candidate as i32) {
+            if info.pbi_ppid == pid {
+                children.push(candidate);
+            }
+        }
+    }
+
+    children
+}
+
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn kill_process_tree_recursive(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
+    for child_pid in children_of_pid(pid) {
+        debug!("Killing child process: {}", child_pid);
+        let outcome = kill_process_tree_recursive(child_pid, ladder);
+        debug!("Child PID {} termination outcome: {:?}", child_pid, outcome);
+    }
+
+    debug!("Killing process tree for PID: {}", pid);
+    TerminationStrategy::for_pid(pid).execute(ladder)
 }
 
-fn kill_process_tree_recursive(pid: u32) {
+#[cfg(feature = "shell-fallback")]
+fn kill_process_tree_recursive(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
     #[cfg(target_os = "macos")]
     {
         if let Ok(output) = Command::new(commands::FIND_CHILDREN)
@@ -94,7 +693,7 @@ fn kill_process_tree_recursive(pid: u32) {
                 for child_pid in child_pids.lines() {
                     if let Ok(child_pid_num) = child_pid.trim().parse::<u32>() {
                         debug!("Killing child process: {}", child_pid_num);
-                        kill_process_tree_recursive(child_pid_num);
+                        kill_process_tree_recursive(child_pid_num, ladder);
                     }
                 }
             }
@@ -113,24 +712,64 @@ fn kill_process_tree_recursive(pid: u32) {
     }
 
     debug!("Killing process tree for PID: {}", pid);
-    let strategy = TerminationStrategy::for_pid(pid);
-    strategy.execute();
+    TerminationStrategy::for_pid(pid).execute(ladder)
+}
+
+#[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+fn kill_process_tree_platform(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
+    for child_pid in win_native::children_of(pid) {
+        kill_process_tree_platform(child_pid, ladder);
+    }
+    TerminationStrategy::for_pid(pid).execute(ladder)
 }
 
-#[cfg(target_os = "windows")]
-fn kill_process_tree_platform(pid: u32) {
-    let _ = Command::new(commands::KILL_TREE)
+#[cfg(all(target_os = "windows", feature = "shell-fallback"))]
+fn kill_process_tree_platform(pid: u32, _ladder: &EscalationLadder) -> TerminationOutcome {
+    let terminated = Command::new(commands::KILL_TREE)
         .args(&[commands::KILL_TREE_ARGS[0], commands::KILL_TREE_ARGS[1], commands::KILL_TREE_ARGS[2], &pid.to_string()])
-        .output();
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false);
+    TerminationOutcome { exited_gracefully: false, signal: None, exit_code: None, escalated: terminated }
 }
 
 #[cfg(not(target_os = "windows"))]
-fn kill_process_tree_platform(pid: u32) {
-    kill_process_tree_recursive(pid);
+fn kill_process_tree_platform(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
+    kill_process_tree_recursive(pid, ladder)
+}
+
+#[cfg(all(target_os = "macos", not(feature = "shell-fallback")))]
+fn kill_process_on_port_platform(port: u16, ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    let mut outcomes = Vec::new();
+    for pid in children_holding_port(port) {
+        info!("Killing process on port {} (PID: {})", port, pid);
+        outcomes.push(TerminationStrategy::for_pid(pid).execute(ladder));
+    }
+    Ok(outcomes)
+}
+
+// TODO(native macOS port-kill): NOT implemented, not just undocumented. Socket-to-PID
+// resolution on macOS goes through proc_pidinfo(PROC_PIDLISTFDS) per candidate process, then
+// proc_pidinfo(PROC_PIDFDSOCKETINFO) per fd to read the in-kernel `socket_info`, which is a
+// deeply nested union (sys/proc_info.h) we don't have a verified binding for without
+// bindgen-generated headers. Until that lands, this is a real regression from the old
+// lsof-based fallback: a native (non-`shell-fallback`) macOS build can never find or kill
+// whatever holds a busy port, so `kill_existing_backend`/`wait_for_port_release` will just
+// time out whenever a stale process is squatting on `BACKEND_PORT`. `warn!` on every call
+// (rather than silently returning no candidates) so that failure is at least diagnosable.
+#[cfg(all(target_os = "macos", not(feature = "shell-fallback")))]
+fn children_holding_port(port: u16) -> Vec<u32> {
+    warn!(
+        "native port-kill is not implemented on macOS for port {}; rebuild with `--features shell-fallback` \
+         to free occupied ports on this platform, otherwise callers waiting on the port will time out",
+        port
+    );
+    Vec::new()
 }
 
-#[cfg(target_os = "macos")]
-fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+#[cfg(all(target_os = "macos", feature = "shell-fallback"))]
+fn kill_process_on_port_platform(port: u16, ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    let mut outcomes = Vec::new();
     if let Ok(output) = Command::new(commands::LSOF)
         .args(&[&format!("{}:{}", commands::LSOF_ARGS[0], port)])
         .output()
@@ -139,17 +778,82 @@ fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Er
             for pid in pids.lines() {
                 if let Ok(pid_num) = pid.trim().parse::<u32>() {
                     info!("Killing process on port {} (PID: {})", port, pid_num);
-                    let strategy = TerminationStrategy::for_pid(pid_num);
-                    strategy.execute();
+                    outcomes.push(TerminationStrategy::for_pid(pid_num).execute(ladder));
                 }
             }
         }
     }
-    Ok(())
+    Ok(outcomes)
+}
+
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+fn kill_process_on_port_platform(port: u16, ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    let mut outcomes = Vec::new();
+    for pid in pids_holding_port(port) {
+        info!("Killing process on port {} (PID: {})", port, pid);
+        outcomes.push(TerminationStrategy::for_pid(pid).execute(ladder));
+    }
+    Ok(outcomes)
 }
 
-#[cfg(target_os = "linux")]
-fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+fn pids_holding_port(port: u16) -> Vec<u32> {
+    // Matches the port against /proc/net/tcp's local_address (hex, little-endian) and then
+    // walks /proc/*/fd looking for a socket inode match, i.e. what `fuser -k PORT/tcp` does.
+    let mut pids = Vec::new();
+
+    let Ok(tcp) = std::fs::read_to_string("/proc/net/tcp") else {
+        return pids;
+    };
+
+    let mut inodes = Vec::new();
+    for line in tcp.lines().skip(1) {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        // fields[0] is the `sl` slot number ("0:"); local_address is the second column.
+        let Some(local_address) = fields.get(1) else { continue };
+        let Some(port_hex) = local_address.split(':').nth(1) else { continue };
+        let Ok(local_port) = u16::from_str_radix(port_hex, 16) else { continue };
+        if local_port != port {
+            continue;
+        }
+        if let Some(inode) = fields.get(9) {
+            inodes.push(inode.to_string());
+        }
+    }
+
+    if inodes.is_empty() {
+        return pids;
+    }
+
+    let Ok(proc_entries) = std::fs::read_dir("/proc") else {
+        return pids;
+    };
+
+    for entry in proc_entries.flatten() {
+        let Ok(pid) = entry.file_name().to_string_lossy().parse::<u32>() else {
+            continue;
+        };
+        let fd_dir = format!("/proc/{}/fd", pid);
+        let Ok(fds) = std::fs::read_dir(&fd_dir) else {
+            continue;
+        };
+        for fd in fds.flatten() {
+            if let Ok(target) = std::fs::read_link(fd.path()) {
+                let target = target.to_string_lossy();
+                if let Some(inode) = target.strip_prefix("socket:[").and_then(|s| s.strip_suffix(']')) {
+                    if inodes.iter().any(|i| i == inode) {
+                        pids.push(pid);
+                    }
+                }
+            }
+        }
+    }
+
+    pids
+}
+
+#[cfg(all(target_os = "linux", feature = "shell-fallback"))]
+fn kill_process_on_port_platform(port: u16, _ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
     let port_spec = format!("{}/tcp", port);
 
     let _ = Command::new(commands::FUSER)
@@ -161,11 +865,22 @@ fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Er
     let _ = Command::new(commands::FUSER)
         .args(&[commands::FUSER_KILL_ARGS[0], &port_spec])
         .output();
-    Ok(())
+    Ok(Vec::new())
+}
+
+#[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+fn kill_process_on_port_platform(port: u16, ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    let mut outcomes = Vec::new();
+    for pid in win_native::pids_holding_port(port) {
+        info!("Killing process on port {} (PID: {})", port, pid);
+        outcomes.push(TerminationStrategy::for_pid(pid).execute(ladder));
+    }
+    Ok(outcomes)
 }
 
-#[cfg(target_os = "windows")]
-fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+#[cfg(all(target_os = "windows", feature = "shell-fallback"))]
+fn kill_process_on_port_platform(port: u16, _ladder: &EscalationLadder) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    let mut outcomes = Vec::new();
     if let Ok(output) = Command::new(commands::NETSTAT)
         .args(commands::NETSTAT_ARGS)
         .output()
@@ -177,19 +892,27 @@ fn kill_process_on_port_platform(port: u16) -> Result<(), Box<dyn std::error::Er
                     if let Some(pid) = line.split_whitespace().last() {
                         if let Ok(pid_num) = pid.parse::<u32>() {
                             info!("Killing process on port {} (PID: {})", port, pid_num);
-                            let _ = Command::new(commands::FORCE_KILL)
+                            let terminated = Command::new(commands::FORCE_KILL)
                                 .args(&[commands::FORCE_KILL_ARGS[0], commands::FORCE_KILL_ARGS[1], &pid_num.to_string()])
-                                .output();
+                                .output()
+                                .map(|o| o.status.success())
+                                .unwrap_or(false);
+                            outcomes.push(TerminationOutcome { exited_gracefully: false, signal: None, exit_code: None, escalated: terminated });
                         }
                     }
                 }
             }
         }
     }
-    Ok(())
+    Ok(outcomes)
 }
 
-#[cfg(target_os = "windows")]
+#[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+fn is_process_running_platform(pid: u32) -> bool {
+    win_native::is_running(pid)
+}
+
+#[cfg(all(target_os = "windows", feature = "shell-fallback"))]
 fn is_process_running_platform(pid: u32) -> bool {
     Command::new(commands::CHECK_PROCESS)
         .args(&[commands::CHECK_PROCESS_ARGS[0], &format!("PID eq {}", pid)])
@@ -201,7 +924,15 @@ fn is_process_running_platform(pid: u32) -> bool {
         .unwrap_or(false)
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn is_process_running_platform(pid: u32) -> bool {
+    // kill(pid, 0) sends no signal but still performs the permission/existence check;
+    // ESRCH means the PID is gone, anything else (success or EPERM) means it's alive.
+    let result = unsafe { libc::kill(pid as pid_t, 0) };
+    result == 0 || unsafe { *libc::__errno_location() } != libc::ESRCH
+}
+
+#[cfg(all(unix, feature = "shell-fallback"))]
 fn is_process_running_platform(pid: u32) -> bool {
     Command::new(commands::KILL_TERM)
         .args(&[commands::CHECK_SIGNAL, &pid.to_string()])
@@ -210,22 +941,370 @@ fn is_process_running_platform(pid: u32) -> bool {
         .unwrap_or(false)
 }
 
-pub fn kill_process_tree(pid: u32) {
-    #[cfg(target_os = "windows")]
-    kill_process_tree_platform(pid);
+/// Kills `pid` and all of its descendants, escalating from a graceful signal to a forced
+/// kill only if the process outlives [`crate::GRACEFUL_TERMINATION_TIMEOUT_MS`]. Returns
+/// the outcome for `pid` itself; descendants' outcomes are logged as they're reaped.
+pub fn kill_process_tree(pid: u32) -> TerminationOutcome {
+    kill_process_tree_with_ladder(pid, &EscalationLadder::default())
+}
 
-    #[cfg(not(target_os = "windows"))]
-    kill_process_tree_platform(pid);
+/// Like [`kill_process_tree`], but escalates through `ladder` instead of the default
+/// SIGTERM-then-SIGKILL sequence.
+pub fn kill_process_tree_with_ladder(pid: u32, ladder: &EscalationLadder) -> TerminationOutcome {
+    if ladder.steps().is_empty() {
+        return TerminationOutcome::default();
+    }
+    kill_process_tree_platform(pid, ladder)
 }
 
-pub fn kill_process_on_port(port: u16) -> Result<(), Box<dyn std::error::Error>> {
-    kill_process_on_port_platform(port)
+/// Kills whatever process(es) are bound to `port`, returning one [`TerminationOutcome`]
+/// per process found.
+pub fn kill_process_on_port(port: u16) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    kill_process_on_port_with_ladder(port, &EscalationLadder::default())
+}
+
+/// Like [`kill_process_on_port`], but escalates through `ladder` instead of the default
+/// SIGTERM-then-SIGKILL sequence.
+pub fn kill_process_on_port_with_ladder(
+    port: u16,
+    ladder: &EscalationLadder,
+) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+    if ladder.steps().is_empty() {
+        return Ok(Vec::new());
+    }
+    kill_process_on_port_platform(port, ladder)
 }
 
 pub fn is_process_running(pid: u32) -> bool {
     is_process_running_platform(pid)
 }
 
+#[cfg(all(unix, not(feature = "shell-fallback")))]
+fn send_graceful_signal_platform(pid: u32) -> bool {
+    unsafe { libc::kill(pid as pid_t, SIGTERM) == 0 }
+}
+
+#[cfg(all(unix, feature = "shell-fallback"))]
+fn send_graceful_signal_platform(pid: u32) -> bool {
+    Command::new(commands::KILL_TERM)
+        .args(&[commands::TERM_SIGNAL, &pid.to_string()])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+#[cfg(all(target_os = "windows", not(feature = "shell-fallback")))]
+fn send_graceful_signal_platform(pid: u32) -> bool {
+    win_native::request_graceful_close(pid)
+}
+
+#[cfg(all(target_os = "windows", feature = "shell-fallback"))]
+fn send_graceful_signal_platform(pid: u32) -> bool {
+    // Without /F, taskkill asks well-behaved windows to close (posting WM_CLOSE) instead
+    // of force-terminating, giving the process a chance to shut down cleanly.
+    Command::new(commands::GRACEFUL_KILL)
+        .args(&[commands::GRACEFUL_KILL_ARGS[0], &pid.to_string()])
+        .output()
+        .map(|output| output.status.success())
+        .unwrap_or(false)
+}
+
+/// Asks `pid` to shut down gracefully (`SIGTERM` on Unix, a console Ctrl+Break event on
+/// Windows) without waiting for it to exit or escalating if it doesn't. Pairs with
+/// [`wait_with_timeout`] for callers that want to own the grace period themselves before
+/// falling back to [`kill_process_tree`].
+pub fn send_graceful_signal(pid: u32) -> bool {
+    send_graceful_signal_platform(pid)
+}
+
+/// Polls `pid` until it exits or `timeout` elapses, returning whether it exited in time.
+/// Never blocks past `timeout`, so it's safe to call from a UI-facing shutdown path.
+pub fn wait_with_timeout(pid: u32, timeout: Duration) -> bool {
+    poll_until_exited(|| !is_process_running_platform(pid), timeout)
+}
+
+/// A process handle pinned at the moment it was discovered, so liveness checks can't be
+/// fooled by PID reuse the way repeated `is_process_running(pid)` polling can.
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+pub struct TrackedProcess(pidfd::Pidfd);
+
+#[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+impl TrackedProcess {
+    /// Pins `pid` via pidfd. Returns `None` on kernels without pidfd support
+    /// (`ENOSYS`) or if the process has already exited.
+    pub fn track(pid: u32) -> Option<Self> {
+        pidfd::Pidfd::open(pid).map(Self)
+    }
+
+    pub fn is_running(&self) -> bool {
+        !self.0.has_exited()
+    }
+}
+
+/// Async variants that await child exit instead of blocking the calling thread on
+/// `std::thread::sleep`, so an app managing many process trees doesn't need a thread per
+/// in-flight teardown.
+#[cfg(not(feature = "shell-fallback"))]
+pub mod r#async {
+    use std::time::Duration;
+
+    #[cfg(unix)]
+    use super::{children_of_pid, reap_exit_status, TerminationOutcome};
+
+    #[cfg(target_os = "linux")]
+    use super::pidfd::Pidfd;
+
+    #[cfg(target_os = "macos")]
+    use super::kqueue::ProcWatch;
+
+    #[cfg(unix)]
+    use super::signal_reaper;
+
+    /// Waits for `pid` to exit on its own — no signal is sent — so the sidecar supervisor
+    /// learns about a crash the moment it happens instead of polling `is_process_running`
+    /// or a curl-based health check on a fixed interval. On Linux this is a single
+    /// pidfd-readability wakeup and on macOS a kqueue `EVFILT_PROC`/`NOTE_EXIT` wakeup, both
+    /// race-free against PID reuse just like [`terminate_async`]; older kernels and other
+    /// Unixes fall back to a `signal_hook`-registered SIGCHLD wakeup, and Windows (which has
+    /// no comparable exit notification) falls back to polling
+    /// [`super::is_process_running_platform`] on an `async-io` timer tick.
+    #[cfg(target_os = "linux")]
+    pub async fn wait_for_exit(pid: u32) -> super::ExitStatus {
+        let Some(fd) = Pidfd::open(pid) else {
+            return wait_for_exit_via_sigchld(pid).await;
+        };
+        let async_fd =
+            async_io::Async::new(fd).expect("registering a pidfd with the reactor is infallible in practice");
+        let _ = async_fd.readable().await;
+        let (exit_code, signal) = reap_exit_status(pid);
+        super::ExitStatus { exit_code, signal }
+    }
+
+    #[cfg(target_os = "macos")]
+    pub async fn wait_for_exit(pid: u32) -> super::ExitStatus {
+        let Some(watch) = ProcWatch::register(pid) else {
+            return wait_for_exit_via_sigchld(pid).await;
+        };
+        let async_watch =
+            async_io::Async::new(watch).expect("registering a kqueue fd with the reactor is infallible in practice");
+        let _ = async_watch.readable().await;
+        let (exit_code, signal) = reap_exit_status(pid);
+        super::ExitStatus { exit_code, signal }
+    }
+
+    #[cfg(all(unix, not(any(target_os = "linux", target_os = "macos"))))]
+    pub async fn wait_for_exit(pid: u32) -> super::ExitStatus {
+        wait_for_exit_via_sigchld(pid).await
+    }
+
+    #[cfg(not(unix))]
+    pub async fn wait_for_exit(pid: u32) -> super::ExitStatus {
+        wait_for_exit_by_polling(pid).await
+    }
+
+    /// SIGCHLD is process-wide rather than per-child, so this wakes on every child exit and
+    /// re-checks whether `pid` specifically is gone, looping until it is.
+    #[cfg(unix)]
+    async fn wait_for_exit_via_sigchld(pid: u32) -> super::ExitStatus {
+        while super::is_process_running_platform(pid) {
+            signal_reaper::next_sigchld().await;
+        }
+        let (exit_code, signal) = reap_exit_status(pid);
+        super::ExitStatus { exit_code, signal }
+    }
+
+    #[cfg(not(unix))]
+    async fn wait_for_exit_by_polling(pid: u32) -> super::ExitStatus {
+        while super::is_process_running_platform(pid) {
+            async_io::Timer::after(Duration::from_millis(200)).await;
+        }
+        super::ExitStatus { exit_code: None, signal: None }
+    }
+
+    /// Races "process exited" against a timeout, exactly like the sync [`super::TerminationStrategy`]
+    /// but without blocking the executor thread. Returns the fd back so the caller can
+    /// reuse it for the next escalation step.
+    #[cfg(target_os = "linux")]
+    async fn await_exit_or_timeout(fd: Pidfd, timeout: Duration) -> (bool, Pidfd) {
+        let async_fd = async_io::Async::new(fd).expect("registering a pidfd with the reactor is infallible in practice");
+
+        let exited = futures_lite::future::or(
+            async {
+                let _ = async_fd.readable().await;
+                true
+            },
+            async {
+                async_io::Timer::after(timeout).await;
+                false
+            },
+        )
+        .await;
+
+        let fd = async_fd.into_inner().expect("pidfd never leaves non-blocking mode");
+        (exited, fd)
+    }
+
+    /// macOS equivalent of [`await_exit_or_timeout`], built on the kqueue `EVFILT_PROC` watch
+    /// instead of a pidfd.
+    #[cfg(target_os = "macos")]
+    async fn await_exit_or_timeout_kqueue(watch: ProcWatch, timeout: Duration) -> (bool, ProcWatch) {
+        let async_watch =
+            async_io::Async::new(watch).expect("registering a kqueue fd with the reactor is infallible in practice");
+
+        let exited = futures_lite::future::or(
+            async {
+                let _ = async_watch.readable().await;
+                true
+            },
+            async {
+                async_io::Timer::after(timeout).await;
+                false
+            },
+        )
+        .await;
+
+        let watch = async_watch.into_inner().expect("kqueue fd never leaves non-blocking mode");
+        (exited, watch)
+    }
+
+    /// Races "process exited" (via the shared SIGCHLD wakeup) against a timeout, for
+    /// platforms with no per-process kernel handle to watch.
+    #[cfg(unix)]
+    async fn await_exit_or_timeout_sigchld(pid: u32, timeout: Duration) -> bool {
+        futures_lite::future::or(
+            async {
+                wait_for_exit_via_sigchld(pid).await;
+                true
+            },
+            async {
+                async_io::Timer::after(timeout).await;
+                false
+            },
+        )
+        .await
+    }
+
+    #[cfg(target_os = "linux")]
+    async fn terminate_async(pid: u32, timeout: Duration) -> TerminationOutcome {
+        let Some(fd) = Pidfd::open(pid) else {
+            // No pidfd support (pre-5.3 kernel) or the process is already gone; reap via
+            // the shared SIGCHLD wakeup instead of blocking the executor on a sync waitpid.
+            return terminate_async_via_sigchld(pid, timeout).await;
+        };
+
+        fd.send_signal(libc::SIGTERM);
+
+        let (exited, fd) = await_exit_or_timeout(fd, timeout).await;
+        if exited {
+            let (exit_code, _) = reap_exit_status(pid);
+            return TerminationOutcome { exited_gracefully: true, signal: Some(libc::SIGTERM), exit_code, escalated: false };
+        }
+
+        fd.send_signal(libc::SIGKILL);
+        let _ = await_exit_or_timeout(fd, Duration::from_millis(crate::PROCESS_KILL_DELAY_MS)).await;
+        let (exit_code, _) = reap_exit_status(pid);
+        TerminationOutcome { exited_gracefully: false, signal: Some(libc::SIGKILL), exit_code, escalated: true }
+    }
+
+    #[cfg(target_os = "macos")]
+    async fn terminate_async(pid: u32, timeout: Duration) -> TerminationOutcome {
+        let Some(watch) = ProcWatch::register(pid) else {
+            return terminate_async_via_sigchld(pid, timeout).await;
+        };
+
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+
+        let (exited, watch) = await_exit_or_timeout_kqueue(watch, timeout).await;
+        if exited {
+            let (exit_code, _) = reap_exit_status(pid);
+            return TerminationOutcome { exited_gracefully: true, signal: Some(libc::SIGTERM), exit_code, escalated: false };
+        }
+
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGKILL);
+        }
+        let _ = await_exit_or_timeout_kqueue(watch, Duration::from_millis(crate::PROCESS_KILL_DELAY_MS)).await;
+        let (exit_code, _) = reap_exit_status(pid);
+        TerminationOutcome { exited_gracefully: false, signal: Some(libc::SIGKILL), exit_code, escalated: true }
+    }
+
+    /// Fallback used on platforms with no per-process kernel handle to watch (older Linux
+    /// kernels without pidfd, and any Unix other than Linux/macOS): signals the process and
+    /// awaits the shared SIGCHLD wakeup instead of polling on a timer.
+    #[cfg(unix)]
+    async fn terminate_async_via_sigchld(pid: u32, timeout: Duration) -> TerminationOutcome {
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+
+        if await_exit_or_timeout_sigchld(pid, timeout).await {
+            let (exit_code, _) = reap_exit_status(pid);
+            return TerminationOutcome { exited_gracefully: true, signal: Some(libc::SIGTERM), exit_code, escalated: false };
+        }
+
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGKILL);
+        }
+        let _ = await_exit_or_timeout_sigchld(pid, Duration::from_millis(crate::PROCESS_KILL_DELAY_MS)).await;
+        let (exit_code, _) = reap_exit_status(pid);
+        TerminationOutcome { exited_gracefully: false, signal: Some(libc::SIGKILL), exit_code, escalated: true }
+    }
+
+    #[cfg(all(unix, not(any(target_os = "linux", target_os = "macos"))))]
+    async fn terminate_async(pid: u32, timeout: Duration) -> TerminationOutcome {
+        terminate_async_via_sigchld(pid, timeout).await
+    }
+
+    /// Windows has no comparable exit notification to await, so this is the one platform
+    /// that still polls [`super::is_process_running_platform`] on an `async-io` timer tick.
+    #[cfg(not(unix))]
+    async fn terminate_async(pid: u32, timeout: Duration) -> TerminationOutcome {
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGTERM);
+        }
+
+        let start = std::time::Instant::now();
+        while start.elapsed() < timeout {
+            if !super::is_process_running_platform(pid) {
+                let (exit_code, _) = reap_exit_status(pid);
+                return TerminationOutcome { exited_gracefully: true, signal: Some(libc::SIGTERM), exit_code, escalated: false };
+            }
+            async_io::Timer::after(Duration::from_millis(50)).await;
+        }
+
+        unsafe {
+            libc::kill(pid as libc::pid_t, libc::SIGKILL);
+        }
+        let (exit_code, _) = reap_exit_status(pid);
+        TerminationOutcome { exited_gracefully: false, signal: Some(libc::SIGKILL), exit_code, escalated: true }
+    }
+
+    /// Async equivalent of [`super::kill_process_tree`]: tears down the whole tree without
+    /// blocking the calling task, so callers can run many of these concurrently.
+    pub async fn kill_process_tree_async(pid: u32) -> TerminationOutcome {
+        for child_pid in children_of_pid(pid) {
+            Box::pin(kill_process_tree_async(child_pid)).await;
+        }
+        terminate_async(pid, Duration::from_millis(crate::GRACEFUL_TERMINATION_TIMEOUT_MS)).await
+    }
+
+    /// Async equivalent of [`super::kill_process_on_port`].
+    pub async fn kill_process_on_port_async(port: u16) -> Result<Vec<TerminationOutcome>, Box<dyn std::error::Error>> {
+        #[cfg(target_os = "linux")]
+        let pids = super::pids_holding_port(port);
+        #[cfg(target_os = "macos")]
+        let pids = super::children_holding_port(port);
+
+        let mut outcomes = Vec::with_capacity(pids.len());
+        for pid in pids {
+            outcomes.push(terminate_async(pid, Duration::from_millis(crate::GRACEFUL_TERMINATION_TIMEOUT_MS)).await);
+        }
+        Ok(outcomes)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,29 +1318,9 @@ mod tests {
     }
 
     #[test]
-    #[cfg(target_os = "windows")]
-    fn test_termination_strategy_for_pid_windows() {
-        let strategy = TerminationStrategy::for_pid(12345);
-        assert_eq!(strategy.graceful_cmd, "taskkill");
-        assert!(strategy.graceful_args.contains(&"/F".to_string()));
-        assert!(strategy.graceful_args.contains(&"/PID".to_string()));
-        assert!(strategy.graceful_args.contains(&"12345".to_string()));
-        assert_eq!(strategy.force_cmd, "taskkill");
-        assert!(strategy.force_args.contains(&"/F".to_string()));
-        assert!(strategy.force_args.contains(&"/PID".to_string()));
-        assert!(strategy.force_args.contains(&"12345".to_string()));
-    }
-
-    #[test]
-    #[cfg(not(target_os = "windows"))]
-    fn test_termination_strategy_for_pid_unix() {
-        let strategy = TerminationStrategy::for_pid(12345);
-        assert_eq!(strategy.graceful_cmd, "kill");
-        assert!(strategy.graceful_args.contains(&"-TERM".to_string()));
-        assert!(strategy.graceful_args.contains(&"12345".to_string()));
-        assert_eq!(strategy.force_cmd, "kill");
-        assert!(strategy.force_args.contains(&"-KILL".to_string()));
-        assert!(strategy.force_args.contains(&"12345".to_string()));
+    fn test_termination_strategy_creation() {
+        let strategy = TerminationStrategy::for_pid(1);
+        assert_eq!(strategy.pid, 1);
     }
 
     #[test]
@@ -271,6 +1330,18 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    #[serial]
+    fn test_wait_with_timeout_already_exited() {
+        assert!(wait_with_timeout(999999, Duration::from_millis(50)));
+    }
+
+    #[test]
+    #[serial]
+    fn test_send_graceful_signal_nonexistent_pid() {
+        assert!(!send_graceful_signal(999999));
+    }
+
     #[test]
     #[serial]
     fn test_kill_process_on_port_unused_port() {
@@ -284,117 +1355,65 @@ mod tests {
         kill_process_tree(999999);
     }
 
-    #[cfg(target_os = "windows")]
-    mod windows_tests {
-        use super::*;
-
-        #[test]
-        fn test_windows_commands() {
-            assert_eq!(commands::KILL_TREE, "taskkill");
-            assert_eq!(commands::CHECK_PROCESS, "tasklist");
-            assert_eq!(commands::NETSTAT, "netstat");
-            assert_eq!(commands::FORCE_KILL, "taskkill");
-        }
-
-        #[test]
-        #[serial]
-        fn test_kill_process_tree_platform_windows() {
-            kill_process_tree_platform(999999);
-        }
-
-        #[test]
-        #[serial]
-        fn test_kill_process_on_port_platform_windows() {
-            let result = kill_process_on_port_platform(65431);
-            assert!(result.is_ok());
-        }
+    #[test]
+    fn test_escalation_ladder_default_is_term_then_kill() {
+        let steps = EscalationLadder::default().steps().to_vec();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].signal, SIGTERM);
+        assert_eq!(steps[1].signal, SIGKILL);
+    }
 
-        #[test]
-        #[serial]
-        fn test_is_process_running_platform_windows() {
-            let result = is_process_running_platform(999999);
-            assert!(!result);
-        }
+    #[test]
+    #[serial]
+    fn test_kill_process_tree_with_custom_ladder_nonexistent_pid() {
+        let ladder = EscalationLadder::new().step(SIGKILL, Duration::from_millis(10));
+        let outcome = kill_process_tree_with_ladder(999999, &ladder);
+        assert!(outcome.exited_gracefully);
+        assert!(!outcome.escalated);
     }
 
-    #[cfg(target_os = "macos")]
-    mod macos_tests {
+    #[cfg(all(target_os = "linux", not(feature = "shell-fallback")))]
+    mod linux_native_tests {
         use super::*;
 
         #[test]
-        fn test_macos_commands() {
-            assert_eq!(commands::FIND_CHILDREN, "pgrep");
-            assert_eq!(commands::KILL_TERM, "kill");
-            assert_eq!(commands::KILL_FORCE, "kill");
-            assert_eq!(commands::LSOF, "lsof");
+        fn test_children_of_pid_nonexistent_pid_has_no_children() {
+            let children = children_of_pid(999999);
+            assert!(children.is_empty());
         }
 
         #[test]
         #[serial]
-        fn test_kill_process_tree_recursive_macos() {
-            kill_process_tree_recursive(999999);
-        }
-
-        #[test]
-        #[serial]
-        fn test_kill_process_on_port_platform_macos() {
-            let result = kill_process_on_port_platform(65430);
-            assert!(result.is_ok());
-        }
-
-        #[test]
-        #[serial]
-        fn test_is_process_running_platform_macos() {
-            let result = is_process_running_platform(999999);
-            assert!(!result);
-        }
-    }
-
-    #[cfg(target_os = "linux")]
-    mod linux_tests {
-        use super::*;
-
-        #[test]
-        fn test_linux_commands() {
-            assert_eq!(commands::KILL_CHILDREN, "pkill");
-            assert_eq!(commands::KILL_TERM, "kill");
-            assert_eq!(commands::KILL_FORCE, "kill");
-            assert_eq!(commands::FUSER, "fuser");
+        fn test_kill_process_tree_recursive_linux() {
+            let outcome = kill_process_tree_recursive(999999, &EscalationLadder::default());
+            assert!(outcome.exited_gracefully);
+            assert!(!outcome.escalated);
         }
 
         #[test]
-        #[serial]
-        fn test_kill_process_tree_recursive_linux() {
-            kill_process_tree_recursive(999999);
+        fn test_tracked_process_nonexistent_pid() {
+            assert!(TrackedProcess::track(999999).is_none());
         }
 
         #[test]
-        #[serial]
-        fn test_kill_process_on_port_platform_linux() {
-            let result = kill_process_on_port_platform(65429);
-            assert!(result.is_ok());
+        fn test_tracked_process_current_process_is_running() {
+            let pid = std::process::id();
+            let tracked = TrackedProcess::track(pid).expect("pidfd_open should succeed for self");
+            assert!(tracked.is_running());
         }
 
         #[test]
         #[serial]
-        fn test_is_process_running_platform_linux() {
-            let result = is_process_running_platform(999999);
-            assert!(!result);
+        fn test_kill_process_tree_async_nonexistent_pid() {
+            let outcome = futures_lite::future::block_on(r#async::kill_process_tree_async(999999));
+            assert!(outcome.exited_gracefully);
+            assert!(!outcome.escalated);
         }
     }
 
     mod general_tests {
         use super::*;
 
-        #[test]
-        fn test_termination_strategy_creation() {
-            let strategy = TerminationStrategy::for_pid(1);
-            assert!(!strategy.graceful_cmd.is_empty());
-            assert!(!strategy.force_cmd.is_empty());
-            assert!(!strategy.graceful_args.is_empty());
-            assert!(!strategy.force_args.is_empty());
-        }
-
         #[test]
         #[serial]
         fn test_public_api_functions() {
@@ -408,4 +1427,4 @@ mod tests {
             assert!(result.is_ok());
         }
     }
-}
\ No newline at end of file
+}